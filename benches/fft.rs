@@ -1,6 +1,8 @@
 //! This benchmarks Fast-Fourier Transform (FFT).
 //! Since it is over a finite field, it is actually the Number Theoretical
-//! Transform (NNT).  It uses the `Fr` scalar field from the BN256 curve.
+//! Transform (NNT).  It uses the `Fr` scalar field from the BN256 curve, and
+//! its `G1` points for the FFT over curve points, as well as the Goldilocks and
+//! BabyBear small fields.  Mersenne-31 is left out: its two-adicity is 1.
 //!
 //! To run this benchmark:
 //!
@@ -14,15 +16,18 @@
 extern crate criterion;
 
 use criterion::{BenchmarkId, Criterion};
-use group::ff::Field;
-use halo2curves::bn256::Fr as Scalar;
-use halo2curves::fft::best_fft;
+use group::ff::{Field, PrimeField};
+use group::{Curve, Group};
+use halo2curves::bn256::{Fr as Scalar, G1Affine, G1};
+use halo2curves::fft::{best_fft, best_fft_affine, best_fft_with_twiddles, FftTwiddles};
+use halo2curves::small_fields::{BabyBear, Goldilocks};
 use rand::{RngCore, SeedableRng};
 use rand_xorshift::XorShiftRng;
 use std::ops::Range;
 use std::time::SystemTime;
 
 const RANGE: Range<u32> = 3..19;
+const POINTS_RANGE: Range<u32> = 3..13;
 const SEED: [u8; 16] = [
     0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc, 0xe5,
 ];
@@ -58,5 +63,70 @@ fn fft(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, fft);
+fn fft_twiddles(c: &mut Criterion) {
+    let max_k = RANGE.max().unwrap_or(16);
+    let mut rng = XorShiftRng::from_seed(SEED);
+    let mut data = generate_data(max_k, &mut rng);
+    let omega = Scalar::random(&mut rng);
+    let mut group = c.benchmark_group("fft_twiddles");
+    for k in RANGE {
+        let twiddles = FftTwiddles::new(omega, k);
+        group.bench_function(BenchmarkId::new("k", k), |b| {
+            let n = 1 << k;
+            assert!(n <= data.len());
+            b.iter(|| {
+                best_fft_with_twiddles(&mut data[..n], &twiddles);
+            });
+        });
+    }
+    group.finish();
+}
+
+fn fft_points(c: &mut Criterion) {
+    let max_k = POINTS_RANGE.max().unwrap_or(10);
+    let mut rng = XorShiftRng::from_seed(SEED);
+    let mut points: Vec<_> = (0..1 << max_k).map(|_| G1::random(&mut rng)).collect();
+    let mut affine = vec![G1Affine::default(); points.len()];
+    G1::batch_normalize(&points, &mut affine);
+    let mut group = c.benchmark_group("fft_points");
+    for k in POINTS_RANGE {
+        let n = 1 << k;
+        let omega = Scalar::ROOT_OF_UNITY.pow_vartime([1 << (Scalar::S - k)]);
+        group.bench_function(BenchmarkId::new("best_fft", k), |b| {
+            b.iter(|| {
+                best_fft(&mut points[..n], omega, k);
+            });
+        });
+        group.bench_function(BenchmarkId::new("best_fft_affine", k), |b| {
+            b.iter(|| {
+                best_fft_affine(&mut affine[..n], omega, k);
+            });
+        });
+    }
+    group.finish();
+}
+
+fn fft_small_field<F: PrimeField>(c: &mut Criterion, name: &str) {
+    let max_k = RANGE.max().unwrap_or(16);
+    let mut rng = XorShiftRng::from_seed(SEED);
+    let mut data: Vec<F> = (0..1 << max_k).map(|_| F::random(&mut rng)).collect();
+    let mut group = c.benchmark_group(name);
+    for k in RANGE {
+        let n = 1 << k;
+        let omega = F::ROOT_OF_UNITY.pow_vartime([1 << (F::S - k)]);
+        group.bench_function(BenchmarkId::new("k", k), |b| {
+            b.iter(|| {
+                best_fft(&mut data[..n], omega, k);
+            });
+        });
+    }
+    group.finish();
+}
+
+fn fft_small_fields(c: &mut Criterion) {
+    fft_small_field::<Goldilocks>(c, "fft_goldilocks");
+    fft_small_field::<BabyBear>(c, "fft_babybear");
+}
+
+criterion_group!(benches, fft, fft_twiddles, fft_points, fft_small_fields);
 criterion_main!(benches);
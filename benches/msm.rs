@@ -1,5 +1,6 @@
 //! This benchmarks Multi Scalar Multiplication (MSM).
-//! It measures `G1` from the BN256 curve.
+//! It measures `G1` from the BN256 curve, and `G2` from the BN256 and
+//! BLS12-381 curves.
 //!
 //! To run this benchmark:
 //!
@@ -15,8 +16,10 @@ extern crate criterion;
 use criterion::{BenchmarkId, Criterion};
 use ff::{Field, PrimeField};
 use group::prime::PrimeCurveAffine;
+use group::{Curve, Group};
 use halo2curves::bn256::{Fr as Scalar, G1Affine as Point};
-use halo2curves::msm::{msm_best, msm_serial};
+use halo2curves::msm::{msm_best, msm_serial, msm_sparse};
+use halo2curves::CurveAffine;
 use rand_core::{RngCore, SeedableRng};
 use rand_xorshift::XorShiftRng;
 use rayon::current_thread_index;
@@ -114,6 +117,33 @@ fn generate_coefficients(k: u8, bits: usize) -> Vec<Scalar> {
     coeffs
 }
 
+/// Witness-like coefficients: `zeros` and `ones` percent of zeros and ones,
+/// the rest split between 16-bit values and full-width scalars.
+fn generate_sparse_coefficients(k: u8, zeros: u32, ones: u32) -> Vec<Scalar> {
+    let n: u64 = {
+        assert!(k < 64);
+        1 << k
+    };
+    assert!(zeros + ones <= 100);
+
+    println!("Generating 2^{k} = {n} sparse coefficients..",);
+    let mut rng = XorShiftRng::from_seed(SEED);
+    (0..n)
+        .map(|_| {
+            let class = rng.next_u32() % 100;
+            if class < zeros {
+                Scalar::ZERO
+            } else if class < zeros + ones {
+                Scalar::ONE
+            } else if class % 2 == 0 {
+                Scalar::from(rng.next_u64() & 0xffff)
+            } else {
+                Scalar::random(&mut rng)
+            }
+        })
+        .collect()
+}
+
 fn msm(c: &mut Criterion) {
     let mut group = c.benchmark_group("msm");
     let max_k = *SINGLECORE_RANGE
@@ -156,5 +186,69 @@ fn msm(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, msm);
+fn msm_sparse_inputs(c: &mut Criterion) {
+    let mut group = c.benchmark_group("msm_sparse");
+    let max_k = *MULTICORE_RANGE.iter().max().unwrap_or(&16);
+    let bases = generate_curvepoints(max_k);
+    // (zeros, ones) percentages
+    let densities = [(50, 40), (80, 15), (30, 30)];
+    let coeffs: Vec<_> = densities
+        .iter()
+        .map(|(zeros, ones)| generate_sparse_coefficients(max_k, *zeros, *ones))
+        .collect();
+
+    for ((zeros, ones), coeffs) in densities.iter().zip(coeffs.iter()) {
+        for k in MULTICORE_RANGE {
+            let id = format!("{zeros}z_{ones}o_{k}");
+            let n: usize = 1 << k;
+            group
+                .bench_function(BenchmarkId::new("best", &id), |b| {
+                    b.iter(|| msm_best(&coeffs[..n], &bases[..n]))
+                })
+                .sample_size(SAMPLE_SIZE);
+            group
+                .bench_function(BenchmarkId::new("sparse", &id), |b| {
+                    b.iter(|| msm_sparse(&coeffs[..n], &bases[..n]))
+                })
+                .sample_size(SAMPLE_SIZE);
+        }
+    }
+    group.finish();
+}
+
+const G2_RANGE: [u8; 5] = [8, 10, 12, 14, 16];
+
+fn msm_g2_curve<C: CurveAffine>(c: &mut Criterion, name: &str) {
+    let mut group = c.benchmark_group(format!("msm_g2_{name}"));
+    let max_k = *G2_RANGE.iter().max().unwrap_or(&16);
+    let mut rng = XorShiftRng::from_seed(SEED);
+
+    println!("Generating 2^{max_k} {name} G2 points..",);
+    let g = C::Curve::random(&mut rng);
+    let points: Vec<_> = std::iter::successors(Some(g), |p| Some(*p + g))
+        .take(1 << max_k)
+        .collect();
+    let mut bases = vec![C::identity(); points.len()];
+    C::Curve::batch_normalize(&points, &mut bases);
+    let coeffs: Vec<_> = (0..bases.len())
+        .map(|_| C::Scalar::random(&mut rng))
+        .collect();
+
+    for k in G2_RANGE {
+        let n: usize = 1 << k;
+        group
+            .bench_function(BenchmarkId::new("multicore", k), |b| {
+                b.iter(|| msm_best(&coeffs[..n], &bases[..n]))
+            })
+            .sample_size(SAMPLE_SIZE);
+    }
+    group.finish();
+}
+
+fn msm_g2(c: &mut Criterion) {
+    msm_g2_curve::<halo2curves::bn256::G2Affine>(c, "bn256");
+    msm_g2_curve::<halo2curves::bls12381::G2Affine>(c, "bls12381");
+}
+
+criterion_group!(benches, msm, msm_sparse_inputs, msm_g2);
 criterion_main!(benches);
@@ -0,0 +1,150 @@
+# This file searches the parameters of the ECFFT domain over the base field of
+# secp256k1 used in src/fft/ecfft.rs: a curve
+#
+#     y^2 = x^3 + a x^2 + b x
+#
+# whose group of points has a cyclic subgroup of order 2^k, a generator of this
+# subgroup and an offset point whose coset of the subgroup has distinct
+# x-coordinates.
+#
+# The order of the group is never computed. The curves are drawn with a single
+# point of order two, (0, 0), so that their 2-Sylow subgroup is cyclic, and the
+# order of this subgroup is found by halving (0, 0) for as long as possible.
+#
+# Usage: python3 script/ecfft_secp256k1.py [log_n] [seed]
+
+import random
+import sys
+
+p = 0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f
+log_n = int(sys.argv[1]) if len(sys.argv) > 1 else 20
+seed = int(sys.argv[2]) if len(sys.argv) > 2 else 0
+
+
+def is_square(x):
+    return x % p == 0 or pow(x, (p - 1) // 2, p) == 1
+
+
+def sqrt(x):
+    # p = 3 mod 4
+    r = pow(x, (p + 1) // 4, p)
+    assert r * r % p == x % p
+    return r
+
+
+# points are pairs (x, y), None is the point at infinity
+
+
+def add(a, b, P, Q):
+    if P is None:
+        return Q
+    if Q is None:
+        return P
+    (x1, y1), (x2, y2) = P, Q
+    if x1 == x2:
+        if (y1 + y2) % p == 0:
+            return None
+        lam = (3 * x1 * x1 + 2 * a * x1 + b) * pow(2 * y1, p - 2, p) % p
+    else:
+        lam = (y2 - y1) * pow(x2 - x1, p - 2, p) % p
+    x3 = (lam * lam - a - x1 - x2) % p
+    return (x3, (lam * (x1 - x3) - y1) % p)
+
+
+def mul(a, b, k, P):
+    R = None
+    while k:
+        if k & 1:
+            R = add(a, b, R, P)
+        P = add(a, b, P, P)
+        k >>= 1
+    return R
+
+
+def quadratic_roots(c1, c0):
+    # the roots in F_p of x^2 + c1 x + c0
+    disc = (c1 * c1 - 4 * c0) % p
+    if not is_square(disc):
+        return []
+    s = sqrt(disc)
+    inv2 = (p + 1) // 2
+    return [(-c1 + s) * inv2 % p, (-c1 - s) * inv2 % p]
+
+
+def halve(a, b, P):
+    # a point Q with 2 Q = P, if any
+    #
+    # [2] is the 2-isogeny phi: (x, y) -> (y^2 / x^2, ...) onto
+    # Y^2 = X^3 - 2 a X^2 + (a^2 - 4 b) X followed by its dual
+    # (X, Y) -> (Y^2 / (4 X^2), ...), and the x-coordinates of the preimages
+    # by both maps are the roots of quadratic polynomials
+    xp = P[0]
+    a2, b2 = -2 * a % p, (a * a - 4 * b) % p
+    for X in quadratic_roots(a2 - 4 * xp, b2):
+        for x in quadratic_roots(a - X, b):
+            y2 = x * (x * x + a * x + b) % p
+            if not is_square(y2):
+                continue
+            Q = (x, sqrt(y2))
+            if add(a, b, Q, Q) == P:
+                return Q
+            Q = (x, (-Q[1]) % p)
+            if add(a, b, Q, Q) == P:
+                return Q
+    return None
+
+
+def search():
+    random.seed(seed)
+    tries = 0
+    while True:
+        tries += 1
+        a, b = random.randrange(p), random.randrange(1, p)
+        if is_square(a * a - 4 * b):
+            continue
+        # cheap test for the first halving, x = +-sqrt(b)
+        if not is_square(b):
+            continue
+        s = sqrt(b)
+        if not (is_square(s * (2 * b + a * s)) or is_square(-s * (2 * b - a * s))):
+            continue
+
+        P, order = (0, 0), 1
+        while order < log_n:
+            Q = halve(a, b, P)
+            if Q is None:
+                break
+            P, order = Q, order + 1
+        if order == log_n:
+            print("tries:", tries, file=sys.stderr)
+            return a, b, P
+
+
+a, b, g = search()
+assert mul(a, b, 1 << (log_n - 1), g) == (0, 0)
+
+# an offset with distinct x-coordinates on its coset, which holds as soon as
+# twice the offset is not in the subgroup
+subgroup = set()
+Q = None
+for _ in range(1 << log_n):
+    Q = add(a, b, Q, g)
+    subgroup.add(Q)
+while True:
+    x = random.randrange(p)
+    y2 = x * (x * x + a * x + b) % p
+    if is_square(y2):
+        offset = (x, sqrt(y2))
+        if add(a, b, offset, offset) not in subgroup:
+            break
+
+print("log_n =", log_n)
+for name, value in [
+    ("a", a),
+    ("b", b),
+    ("generator.x", g[0]),
+    ("generator.y", g[1]),
+    ("offset.x", offset[0]),
+    ("offset.y", offset[1]),
+]:
+    print(name, "=", "0x%064x" % value)
@@ -70,3 +70,15 @@ pub(crate) fn mul_512(a: [u64; 4], b: [u64; 4]) -> [u64; 8] {
 
     [r0, r1, r2, r3, r4, r5, r6, carry_out]
 }
+
+/// Returns the product `a * b` divided by $2^{256}$, rounded to the nearest
+/// integer.
+#[inline(always)]
+pub(crate) fn mul_512_round_high(a: [u64; 4], b: [u64; 4]) -> [u64; 4] {
+    let r = mul_512(a, b);
+    let (r4, carry) = macx(r[4], r[3] >> 63, 1);
+    let (r5, carry) = macx(r[5], carry, 1);
+    let (r6, carry) = macx(r[6], carry, 1);
+    let (r7, _) = macx(r[7], carry, 1);
+    [r4, r5, r6, r7]
+}
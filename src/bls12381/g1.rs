@@ -26,7 +26,7 @@ new_curve_impl!(
     A,
     B,
     "bls12381_g1",
-    |domain_prefix| hash_to_curve(domain_prefix, hash_to_curve_suite(b"BLS12381G1_XMD:SHA-256_SSWU_RO_")),
+    |domain_prefix| hash_to_curve(domain_prefix, crate::hash_to_curve::Suite::<G1, sha2::Sha256, 64>::bls12381_g1_sswu(b"BLS12381G1_XMD:SHA-256_SSWU_RO_")),
     crate::serde::CompressedFlagConfig::ThreeSpare
 );
 
@@ -91,34 +91,39 @@ impl G1 {
     }
 }
 
-fn hash_to_curve_suite(domain: &[u8]) -> crate::hash_to_curve::Suite<G1, sha2::Sha256, 64> {
-    const SSWU_Z: Fq = Fq::from_raw([11, 0, 0, 0, 0, 0]);
-
-    pub const ISO_A: Fq = Fq([
-        0x2f65_aa0e_9af5_aa51,
-        0x8646_4c2d_1e84_16c3,
-        0xb85c_e591_b7bd_31e2,
-        0x27e1_1c91_b5f2_4e7c,
-        0x2837_6eda_6bfc_1835,
-        0x1554_55c3_e507_1d85,
-    ]);
-
-    pub const ISO_B: Fq = Fq([
-        0xfb99_6971_fe22_a1e0,
-        0x9aa9_3eb3_5b74_2d6f,
-        0x8c47_6013_de99_c5c4,
-        0x873e_27c3_a221_e571,
-        0xca72_b5e4_5a52_d888,
-        0x0682_4061_418a_386b,
-    ]);
-
-    let iso_map = crate::hash_to_curve::Iso {
-        a: ISO_A,
-        b: ISO_B,
-        map: Box::new(iso_map),
-    };
+impl<D: crate::hash_to_curve::ExpandMessage> crate::hash_to_curve::Suite<G1, D, 64> {
+    /// The simplified SWU suite of BLS12-381 G1, under the domain separation tag
+    /// `domain`, e.g. `BLS12381G1_XMD:SHA-256_SSWU_RO_` with `D = Sha256`.
+    pub fn bls12381_g1_sswu(domain: &[u8]) -> Self {
+        const SSWU_Z: Fq = Fq::from_raw([11, 0, 0, 0, 0, 0]);
+
+        pub const ISO_A: Fq = Fq([
+            0x2f65_aa0e_9af5_aa51,
+            0x8646_4c2d_1e84_16c3,
+            0xb85c_e591_b7bd_31e2,
+            0x27e1_1c91_b5f2_4e7c,
+            0x2837_6eda_6bfc_1835,
+            0x1554_55c3_e507_1d85,
+        ]);
+
+        pub const ISO_B: Fq = Fq([
+            0xfb99_6971_fe22_a1e0,
+            0x9aa9_3eb3_5b74_2d6f,
+            0x8c47_6013_de99_c5c4,
+            0x873e_27c3_a221_e571,
+            0xca72_b5e4_5a52_d888,
+            0x0682_4061_418a_386b,
+        ]);
+
+        let iso_map = crate::hash_to_curve::Iso {
+            a: ISO_A,
+            b: ISO_B,
+            map: Box::new(iso_map),
+        };
 
-    crate::hash_to_curve::Suite::new(domain, SSWU_Z, crate::hash_to_curve::Method::SSWU(iso_map))
+        Self::new(domain, SSWU_Z, crate::hash_to_curve::Method::SSWU(iso_map))
+            .expect("BLS12-381 G1 SSWU parameters are valid")
+    }
 }
 
 /// Maps an iso-G1 point to a G1 point.
@@ -163,13 +168,29 @@ fn iso_map(x: Fq, y: Fq, z: Fq) -> G1 {
 }
 
 #[allow(clippy::type_complexity)]
-pub(crate) fn hash_to_curve<'a>(
+pub(crate) fn hash_to_curve<'a, D: crate::hash_to_curve::ExpandMessage + 'a>(
     domain_prefix: &'a str,
-    suite: crate::hash_to_curve::Suite<G1, sha2::Sha256, 64>,
+    suite: crate::hash_to_curve::Suite<G1, D, 64>,
 ) -> Box<dyn Fn(&[u8]) -> G1 + 'a> {
     Box::new(move |message| suite.hash_to_curve(domain_prefix, message).clear_cofactor())
 }
 
+impl G1 {
+    /// Returns the `BLS12381G1_XMD:SHA-256_SSWU_NU_` encoding to the curve, the cheaper but
+    /// non-uniform counterpart of [`CurveExt::hash_to_curve`].
+    #[allow(clippy::type_complexity)]
+    pub fn encode_to_curve<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> Self + 'a> {
+        let suite = crate::hash_to_curve::Suite::<Self, sha2::Sha256, 64>::bls12381_g1_sswu(
+            b"BLS12381G1_XMD:SHA-256_SSWU_NU_",
+        );
+        Box::new(move |message| {
+            suite
+                .encode_to_curve(domain_prefix, message)
+                .clear_cofactor()
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::arithmetic::CurveEndo;
@@ -233,6 +254,82 @@ mod test {
         test.run("QUUX-V01-CS02-with-");
         });
     }
+
+    #[test]
+    fn test_hash_to_curve_xof() {
+        use crate::hash_to_curve::{ExpandMsgXof, Suite};
+        use crate::tests::hex_to_field;
+        use sha3::Shake256;
+
+        // There are no published vectors for this suite, the field elements
+        // are reduced from the output of `expand_message_xof`.
+        let suite = Suite::<G1, ExpandMsgXof<Shake256>, 64>::bls12381_g1_sswu(
+            b"BLS12381G1_XOF:SHAKE-256_SSWU_RO_",
+        );
+        let u = suite.hash_to_field(b"QUUX-V01-CS02-with-", b"abc", 2);
+        assert_eq!(
+            u[0],
+            hex_to_field("0aeb330f00940c191fc56844f6c04c170b1d6d042b097399d6688441b8ab17a1c6e34620179ea55f55c9b8146a1fb004")
+        );
+        assert_eq!(
+            u[1],
+            hex_to_field("04510da5072a0756c409ff1a5c40c205d98238c437d20a471af8fe57e365c0d510c4444bb5040ac0e4c4ab449b514d08")
+        );
+
+        let hasher = hash_to_curve("QUUX-V01-CS02-with-", suite);
+        for message in [&b""[..], b"abc", b"abcdef0123456789"] {
+            let point = hasher(message);
+            assert!(bool::from(point.is_on_curve()));
+            assert!(bool::from(point.is_torsion_free()));
+            assert_ne!(point, G1::hash_to_curve("QUUX-V01-CS02-with-")(message));
+        }
+    }
+
+    #[test]
+    fn test_encode_to_curve() {
+        // Test vectors are taken from
+        // https://www.rfc-editor.org/rfc/rfc9380.html#name-bls12381g1_xmdsha-256_sswu_nu_
+        let encoder = G1::encode_to_curve("QUUX-V01-CS02-with-");
+        [
+            TestH2C::<G1Affine>::new(
+                b"",
+                crate::tests::point_from_hex(
+                    "184bb665c37ff561a89ec2122dd343f20e0f4cbcaec84e3c3052ea81d1834e192c426074b02ed3dca4e7676ce4ce48ba",
+                    "04407b8d35af4dacc809927071fc0405218f1401a6d15af775810e4e460064bcc9468beeba82fdc751be70476c888bf3",
+                ),
+            ),
+            TestH2C::<G1Affine>::new(
+                b"abc",
+                crate::tests::point_from_hex(
+                    "009769f3ab59bfd551d53a5f846b9984c59b97d6842b20a2c565baa167945e3d026a3755b6345df8ec7e6acb6868ae6d",
+                    "1532c00cf61aa3d0ce3e5aa20c3b531a2abd2c770a790a2613818303c6b830ffc0ecf6c357af3317b9575c567f11cd2c",
+                ),
+            ),
+            TestH2C::<G1Affine>::new(
+                b"abcdef0123456789",
+                crate::tests::point_from_hex(
+                    "1974dbb8e6b5d20b84df7e625e2fbfecb2cdb5f77d5eae5fb2955e5ce7313cae8364bc2fff520a6c25619739c6bdcb6a",
+                    "15f9897e11c6441eaa676de141c8d83c37aab8667173cbe1dfd6de74d11861b961dccebcd9d289ac633455dfcc7013a3",
+                ),
+            ),
+            TestH2C::<G1Affine>::new(
+                b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+                crate::tests::point_from_hex(
+                    "0a7a047c4a8397b3446450642c2ac64d7239b61872c9ae7a59707a8f4f950f101e766afe58223b3bff3a19a7f754027c",
+                    "1383aebba1e4327ccff7cf9912bda0dbc77de048b71ef8c8a81111d71dc33c5e3aa6edee9cf6f5fe525d50cc50b77cc9",
+                ),
+            ),
+            TestH2C::<G1Affine>::new(
+                b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                crate::tests::point_from_hex(
+                    "0e7a16a975904f131682edbb03d9560d3e48214c9986bd50417a77108d13dc957500edf96462a3d01e62dc6cd468ef11",
+                    "0ae89e677711d05c30a48d6d75e76ca9fb70fe06c6dd6ff988683d89ccde29ac7d46c53bb97a59b1901abf1db66052db",
+                ),
+            ),
+        ]
+        .iter()
+        .for_each(|test| test.run_with(&encoder));
+    }
 }
 
 /// Coefficients of the 11-isogeny x map's numerator
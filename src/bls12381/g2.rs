@@ -74,7 +74,7 @@ new_curve_impl!(
     G2_A,
     G2_B,
     "bls12381_g2",
-    |domain_prefix| hash_to_curve(domain_prefix, hash_to_curve_suite(b"BLS12381G2_XMD:SHA-256_SSWU_RO_")),
+    |domain_prefix| hash_to_curve(domain_prefix, crate::hash_to_curve::Suite::bls12381_g2_sswu(b"BLS12381G2_XMD:SHA-256_SSWU_RO_")),
     crate::serde::CompressedFlagConfig::ThreeSpare
 
 );
@@ -225,63 +225,68 @@ impl G2 {
     }
 }
 
-fn hash_to_curve_suite(domain: &[u8]) -> crate::hash_to_curve::Suite<G2, sha2::Sha256, 128> {
-    const SSWU_Z: Fq2 = Fq2 {
-        c0: Fq([
-            0x87eb_ffff_fff9_555c,
-            0x656f_ffe5_da8f_fffa,
-            0x0fd0_7493_45d3_3ad2,
-            0xd951_e663_0665_76f4,
-            0xde29_1a3d_41e9_80d3,
-            0x0815_664c_7dfe_040d,
-        ]),
-        c1: Fq([
-            0x43f5_ffff_fffc_aaae,
-            0x32b7_fff2_ed47_fffd,
-            0x07e8_3a49_a2e9_9d69,
-            0xeca8_f331_8332_bb7a,
-            0xef14_8d1e_a0f4_c069,
-            0x040a_b326_3eff_0206,
-        ]),
-    };
+impl<D: crate::hash_to_curve::ExpandMessage> crate::hash_to_curve::Suite<G2, D, 128> {
+    /// The simplified SWU suite of BLS12-381 G2, under the domain separation tag
+    /// `domain`, e.g. `BLS12381G2_XMD:SHA-256_SSWU_RO_` with `D = Sha256`.
+    pub fn bls12381_g2_sswu(domain: &[u8]) -> Self {
+        const SSWU_Z: Fq2 = Fq2 {
+            c0: Fq([
+                0x87eb_ffff_fff9_555c,
+                0x656f_ffe5_da8f_fffa,
+                0x0fd0_7493_45d3_3ad2,
+                0xd951_e663_0665_76f4,
+                0xde29_1a3d_41e9_80d3,
+                0x0815_664c_7dfe_040d,
+            ]),
+            c1: Fq([
+                0x43f5_ffff_fffc_aaae,
+                0x32b7_fff2_ed47_fffd,
+                0x07e8_3a49_a2e9_9d69,
+                0xeca8_f331_8332_bb7a,
+                0xef14_8d1e_a0f4_c069,
+                0x040a_b326_3eff_0206,
+            ]),
+        };
 
-    const ISO_A: Fq2 = Fq2 {
-        c0: Fq::zero(),
-        c1: Fq([
-            0xe53a_0000_0313_5242,
-            0x0108_0c0f_def8_0285,
-            0xe788_9edb_e340_f6bd,
-            0x0b51_3751_2631_0601,
-            0x02d6_9857_17c7_44ab,
-            0x1220_b4e9_79ea_5467,
-        ]),
-    };
+        const ISO_A: Fq2 = Fq2 {
+            c0: Fq::zero(),
+            c1: Fq([
+                0xe53a_0000_0313_5242,
+                0x0108_0c0f_def8_0285,
+                0xe788_9edb_e340_f6bd,
+                0x0b51_3751_2631_0601,
+                0x02d6_9857_17c7_44ab,
+                0x1220_b4e9_79ea_5467,
+            ]),
+        };
 
-    const ISO_B: Fq2 = Fq2 {
-        c0: Fq([
-            0x22ea_0000_0cf8_9db2,
-            0x6ec8_32df_7138_0aa4,
-            0x6e1b_9440_3db5_a66e,
-            0x75bf_3c53_a794_73ba,
-            0x3dd3_a569_412c_0a34,
-            0x125c_db5e_74dc_4fd1,
-        ]),
-        c1: Fq([
-            0x22ea_0000_0cf8_9db2,
-            0x6ec8_32df_7138_0aa4,
-            0x6e1b_9440_3db5_a66e,
-            0x75bf_3c53_a794_73ba,
-            0x3dd3_a569_412c_0a34,
-            0x125c_db5e_74dc_4fd1,
-        ]),
-    };
-    let iso_map = crate::hash_to_curve::Iso {
-        a: ISO_A,
-        b: ISO_B,
-        map: Box::new(iso_map),
-    };
-
-    crate::hash_to_curve::Suite::new(domain, SSWU_Z, crate::hash_to_curve::Method::SSWU(iso_map))
+        const ISO_B: Fq2 = Fq2 {
+            c0: Fq([
+                0x22ea_0000_0cf8_9db2,
+                0x6ec8_32df_7138_0aa4,
+                0x6e1b_9440_3db5_a66e,
+                0x75bf_3c53_a794_73ba,
+                0x3dd3_a569_412c_0a34,
+                0x125c_db5e_74dc_4fd1,
+            ]),
+            c1: Fq([
+                0x22ea_0000_0cf8_9db2,
+                0x6ec8_32df_7138_0aa4,
+                0x6e1b_9440_3db5_a66e,
+                0x75bf_3c53_a794_73ba,
+                0x3dd3_a569_412c_0a34,
+                0x125c_db5e_74dc_4fd1,
+            ]),
+        };
+        let iso_map = crate::hash_to_curve::Iso {
+            a: ISO_A,
+            b: ISO_B,
+            map: Box::new(iso_map),
+        };
+
+        Self::new(domain, SSWU_Z, crate::hash_to_curve::Method::SSWU(iso_map))
+            .expect("BLS12-381 G2 SSWU parameters are valid")
+    }
 }
 
 /// Maps an iso-G1 point to a G1 point.
@@ -327,6 +332,22 @@ pub(crate) fn hash_to_curve<'a>(
     Box::new(move |message| suite.hash_to_curve(domain_prefix, message).clear_cofactor())
 }
 
+impl G2 {
+    /// Returns the `BLS12381G2_XMD:SHA-256_SSWU_NU_` encoding to the curve, the cheaper but
+    /// non-uniform counterpart of [`CurveExt::hash_to_curve`].
+    #[allow(clippy::type_complexity)]
+    pub fn encode_to_curve<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> Self + 'a> {
+        let suite = crate::hash_to_curve::Suite::<Self, sha2::Sha256, 128>::bls12381_g2_sswu(
+            b"BLS12381G2_XMD:SHA-256_SSWU_NU_",
+        );
+        Box::new(move |message| {
+            suite
+                .encode_to_curve(domain_prefix, message)
+                .clear_cofactor()
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -347,18 +368,18 @@ mod test {
         ));
     }
 
+    pub(crate) fn point_from_hex(x0: &str, x1: &str, y0: &str, y1: &str) -> G2Affine {
+        let x0: Fq = crate::tests::hex_to_field(x0);
+        let x1: Fq = crate::tests::hex_to_field(x1);
+        let x = Fq2 { c0: x0, c1: x1 };
+        let y0: Fq = crate::tests::hex_to_field(y0);
+        let y1: Fq = crate::tests::hex_to_field(y1);
+        let y = Fq2 { c0: y0, c1: y1 };
+        G2Affine::from_xy(x, y).unwrap()
+    }
+
     #[test]
     fn test_hash_to_curve() {
-        pub(crate) fn point_from_hex(x0: &str, x1: &str, y0: &str, y1: &str) -> G2Affine {
-            let x0: Fq = crate::tests::hex_to_field(x0);
-            let x1: Fq = crate::tests::hex_to_field(x1);
-            let x = Fq2 { c0: x0, c1: x1 };
-            let y0: Fq = crate::tests::hex_to_field(y0);
-            let y1: Fq = crate::tests::hex_to_field(y1);
-            let y = Fq2 { c0: y0, c1: y1 };
-            G2Affine::from_xy(x, y).unwrap()
-        }
-
         struct Test {
             msg: &'static [u8],
             expect: G2Affine,
@@ -427,6 +448,44 @@ mod test {
             test.run("QUUX-V01-CS02-with-");
         });
     }
+
+    #[test]
+    fn test_encode_to_curve() {
+        // Test vectors are taken from
+        // https://www.rfc-editor.org/rfc/rfc9380.html#name-bls12381g2_xmdsha-256_sswu_nu_
+        let encoder = G2::encode_to_curve("QUUX-V01-CS02-with-");
+        [
+            (
+                &b""[..],
+                point_from_hex(
+                    "00e7f4568a82b4b7dc1f14c6aaa055edf51502319c723c4dc2688c7fe5944c213f510328082396515734b6612c4e7bb7",
+                    "126b855e9e69b1f691f816e48ac6977664d24d99f8724868a184186469ddfd4617367e94527d4b74fc86413483afb35b",
+                    "0caead0fd7b6176c01436833c79d305c78be307da5f6af6c133c47311def6ff1e0babf57a0fb5539fce7ee12407b0a42",
+                    "1498aadcf7ae2b345243e281ae076df6de84455d766ab6fcdaad71fab60abb2e8b980a440043cd305db09d283c895e3d",
+                ),
+            ),
+            (
+                &b"abc"[..],
+                point_from_hex(
+                    "108ed59fd9fae381abfd1d6bce2fd2fa220990f0f837fa30e0f27914ed6e1454db0d1ee957b219f61da6ff8be0d6441f",
+                    "0296238ea82c6d4adb3c838ee3cb2346049c90b96d602d7bb1b469b905c9228be25c627bffee872def773d5b2a2eb57d",
+                    "033f90f6057aadacae7963b0a0b379dd46750c1c94a6357c99b65f63b79e321ff50fe3053330911c56b6ceea08fee656",
+                    "153606c417e59fb331b7ae6bce4fbf7c5190c33ce9402b5ebe2b70e44fca614f3f1382a3625ed5493843d0b0a652fc3f",
+                ),
+            ),
+            (
+                &b"abcdef0123456789"[..],
+                point_from_hex(
+                    "038af300ef34c7759a6caaa4e69363cafeed218a1f207e93b2c70d91a1263d375d6730bd6b6509dcac3ba5b567e85bf3",
+                    "0da75be60fb6aa0e9e3143e40c42796edf15685cafe0279afd2a67c3dff1c82341f17effd402e4f1af240ea90f4b659b",
+                    "19b148cbdf163cf0894f29660d2e7bfb2b68e37d54cc83fd4e6e62c020eaa48709302ef8e746736c0e19342cc1ce3df4",
+                    "0492f4fed741b073e5a82580f7c663f9b79e036b70ab3e51162359cec4e77c78086fe879b65ca7a47d34374c8315ac5e",
+                ),
+            ),
+        ]
+        .iter()
+        .for_each(|(msg, expect)| assert_eq!(encoder(msg).to_affine(), *expect));
+    }
 }
 
 /// Coefficients of the 3-isogeny x map's numerator
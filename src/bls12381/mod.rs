@@ -34,3 +34,6 @@ use crate::arithmetic::{mul_512, sbb, CurveEndo, EndoParameters};
 use ff::{PrimeField, WithSmallOrderMulGroup};
 crate::endo!(G1, Fr, ENDO_PARAMS);
 crate::endo!(G2, Fr, ENDO_PARAMS);
+
+crate::impl_curve_msm!(G1Affine, "glv");
+crate::impl_curve_msm!(G2Affine, "glv");
@@ -145,6 +145,9 @@ const ENDO_PARAMS_BN: EndoParameters = EndoParameters {
 
 endo!(G1, Fr, ENDO_PARAMS_BN);
 
+crate::impl_curve_msm!(G1Affine, "glv");
+crate::impl_curve_msm!(G2Affine);
+
 impl group::cofactor::CofactorGroup for G1 {
     type Subgroup = G1;
 
@@ -242,11 +245,17 @@ impl G1 {
     const SVDW_Z: Fq = Fq::ONE;
 
     fn default_hash_to_curve_suite() -> crate::hash_to_curve::Suite<Self, sha2::Sha256, 48> {
-        crate::hash_to_curve::Suite::<G1, sha2::Sha256, 48>::new(
-            b"BN254G1_XMD:SHA-256_SVDW_RO_",
-            Self::SVDW_Z,
-            crate::hash_to_curve::Method::SVDW,
-        )
+        crate::hash_to_curve::Suite::bn254_g1_svdw(b"BN254G1_XMD:SHA-256_SVDW_RO_")
+    }
+
+    /// Returns the `BN254G1_XMD:SHA-256_SVDW_NU_` encoding to the curve, the
+    /// cheaper but non-uniform counterpart of [`CurveExt::hash_to_curve`].
+    #[allow(clippy::type_complexity)]
+    pub fn encode_to_curve<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> Self + 'a> {
+        let suite = crate::hash_to_curve::Suite::<Self, sha2::Sha256, 48>::bn254_g1_svdw(
+            b"BN254G1_XMD:SHA-256_SVDW_NU_",
+        );
+        Box::new(move |message| suite.encode_to_curve(domain_prefix, message))
     }
 }
 
@@ -254,11 +263,39 @@ impl G2 {
     const SVDW_Z: Fq2 = Fq2::ONE;
 
     fn default_hash_to_curve_suite() -> crate::hash_to_curve::Suite<Self, sha2::Sha256, 96> {
-        crate::hash_to_curve::Suite::<G2, sha2::Sha256, 96>::new(
-            b"BN254G2_XMD:SHA-256_SVDW_RO_",
-            Self::SVDW_Z,
-            crate::hash_to_curve::Method::SVDW,
-        )
+        crate::hash_to_curve::Suite::bn254_g2_svdw(b"BN254G2_XMD:SHA-256_SVDW_RO_")
+    }
+
+    /// Returns the `BN254G2_XMD:SHA-256_SVDW_NU_` encoding to the curve, the
+    /// cheaper but non-uniform counterpart of [`CurveExt::hash_to_curve`].
+    #[allow(clippy::type_complexity)]
+    pub fn encode_to_curve<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> Self + 'a> {
+        let suite = crate::hash_to_curve::Suite::<Self, sha2::Sha256, 96>::bn254_g2_svdw(
+            b"BN254G2_XMD:SHA-256_SVDW_NU_",
+        );
+        Box::new(move |message| {
+            suite
+                .encode_to_curve(domain_prefix, message)
+                .clear_cofactor()
+        })
+    }
+}
+
+impl<D: crate::hash_to_curve::ExpandMessage> crate::hash_to_curve::Suite<G1, D, 48> {
+    /// The Shallue-van de Woestijne suite of BN254 G1, under the domain separation
+    /// tag `domain`, e.g. `BN254G1_XMD:SHA-256_SVDW_RO_` with `D = Sha256`.
+    pub fn bn254_g1_svdw(domain: &[u8]) -> Self {
+        Self::new(domain, G1::SVDW_Z, crate::hash_to_curve::Method::SVDW)
+            .expect("BN254 G1 SVDW parameters are valid")
+    }
+}
+
+impl<D: crate::hash_to_curve::ExpandMessage> crate::hash_to_curve::Suite<G2, D, 96> {
+    /// The Shallue-van de Woestijne suite of BN254 G2, under the domain separation
+    /// tag `domain`, e.g. `BN254G2_XMD:SHA-256_SVDW_RO_` with `D = Sha256`.
+    pub fn bn254_g2_svdw(domain: &[u8]) -> Self {
+        Self::new(domain, G2::SVDW_Z, crate::hash_to_curve::Method::SVDW)
+            .expect("BN254 G2 SVDW parameters are valid")
     }
 }
 
@@ -414,4 +451,19 @@ mod test {
             test.run("QUUX-V01-CS02-with-");
         });
     }
+
+    #[test]
+    fn test_encode_to_curve() {
+        // There are no published vectors for the NU suites of BN254, so only
+        // check that the encodings land in the prime-order subgroup and differ
+        // from the RO ones.
+        let (g1, g2) = (G1::encode_to_curve("test"), G2::encode_to_curve("test"));
+        for message in [&b""[..], b"abc", b"abcdef0123456789"] {
+            let (p, q) = (g1(message), g2(message));
+            assert!(bool::from(p.is_on_curve()));
+            assert!(bool::from(q.is_on_curve()) && bool::from(q.is_torsion_free()));
+            assert_ne!(p, G1::hash_to_curve("test")(message));
+            assert_ne!(q, G2::hash_to_curve("test")(message));
+        }
+    }
 }
@@ -14,6 +14,7 @@ impl_field!(
 );
 
 crate::extend_field_legendre!(Fr);
+crate::impl_extension_degree!(Fr, 1);
 crate::impl_binops_calls!(Fr);
 crate::impl_binops_additive!(Fr, Fr);
 crate::impl_binops_multiplicative!(Fr, Fr);
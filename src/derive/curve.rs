@@ -28,10 +28,10 @@ macro_rules! endo {
                 };
 
                 let input = to_limbs(&k);
-                let c1 = mul_512($params.gamma2, input);
-                let c2 = mul_512($params.gamma1, input);
-                let c1 = [c1[4], c1[5], c1[6], c1[7]];
-                let c2 = [c2[4], c2[5], c2[6], c2[7]];
+                // rounded rather than truncated, or the halves may exceed
+                // 128 bits for lattices with a longer basis, such as secp256k1
+                let c1 = $crate::arithmetic::mul_512_round_high($params.gamma2, input);
+                let c2 = $crate::arithmetic::mul_512_round_high($params.gamma1, input);
                 let q1 = mul_512(c1, $params.b1);
                 let q2 = mul_512(c2, $params.b2);
                 let q1 = $field::from_raw([q1[0], q1[1], q1[2], q1[3]]);
@@ -49,6 +49,20 @@ macro_rules! endo {
     };
 }
 
+#[macro_export]
+macro_rules! impl_curve_msm {
+    ($affine:ident) => {
+        impl $crate::msm::CurveMsm for $affine {}
+    };
+    ($affine:ident, "glv") => {
+        impl $crate::msm::CurveMsm for $affine {
+            fn msm(coeffs: &[Self::Scalar], bases: &[Self]) -> Self::Curve {
+                $crate::msm::msm_glv(coeffs, bases)
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! new_curve_impl {
     (($($privacy:tt)*),
@@ -2,3 +2,5 @@
 pub mod common;
 #[macro_use]
 pub mod tower;
+#[macro_use]
+pub mod small;
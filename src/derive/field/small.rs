@@ -0,0 +1,254 @@
+/// Implements the `ff` traits, the operators and the serialization of a prime
+/// field whose elements fit in a single machine word `$inner`.
+///
+/// The field is expected to be a tuple struct `$field($inner)` providing:
+/// - the modulus as `$field::MODULUS_INNER` and the byte size of `$inner` as
+///   `$field::SIZE`,
+/// - the `const fn`s `from_canonical` and `to_canonical`, converting from and
+///   to the canonical representative in `[0, p)`,
+/// - the `const fn`s `zero` and `one`,
+/// - the methods `add`, `sub`, `mul`, `neg`, `double`, `square` and `sqrt`.
+///
+/// The constants are given by their canonical representatives, and
+/// `FromUniformBytes<L>` is implemented for every `L` of `from_uniform`, which
+/// must be multiples of 4.
+#[macro_export]
+macro_rules! impl_small_field {
+    (
+        $field:ident,
+        $inner:ty,
+        modulus = $modulus:literal,
+        mul_gen = $mul_gen:expr,
+        root_of_unity = $root_of_unity:expr,
+        root_of_unity_inv = $root_of_unity_inv:expr,
+        delta = $delta:expr,
+        zeta = $zeta:expr,
+        from_uniform = [$($from_uniform:expr),*]
+    ) => {
+        impl core::fmt::Debug for $field {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "0x{:0width$x}",
+                    self.to_canonical(),
+                    width = 2 * Self::SIZE
+                )
+            }
+        }
+
+        impl subtle::ConstantTimeEq for $field {
+            fn ct_eq(&self, other: &Self) -> subtle::Choice {
+                subtle::ConstantTimeEq::ct_eq(&self.0, &other.0)
+            }
+        }
+
+        impl subtle::ConditionallySelectable for $field {
+            fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+                $field(
+                    <$inner as subtle::ConditionallySelectable>::conditional_select(
+                        &a.0, &b.0, choice,
+                    ),
+                )
+            }
+        }
+
+        impl core::cmp::PartialOrd for $field {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl core::cmp::Ord for $field {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.to_canonical().cmp(&other.to_canonical())
+            }
+        }
+
+        impl From<u64> for $field {
+            fn from(val: u64) -> $field {
+                $field::from_canonical((val % Self::MODULUS_INNER as u64) as $inner)
+            }
+        }
+
+        impl From<bool> for $field {
+            fn from(val: bool) -> $field {
+                $field::from_canonical(val as $inner)
+            }
+        }
+
+        $crate::impl_extension_degree!($field, 1);
+
+        impl $crate::ff_ext::Legendre for $field {
+            fn legendre(&self) -> i64 {
+                let t = ff::Field::pow_vartime(self, [(Self::MODULUS_INNER as u64 - 1) / 2]);
+                if t == Self::one() {
+                    1
+                } else if t == Self::zero() {
+                    0
+                } else {
+                    -1
+                }
+            }
+        }
+
+        impl ff::Field for $field {
+            const ZERO: Self = Self::zero();
+            const ONE: Self = Self::one();
+
+            fn random(mut rng: impl rand_core::RngCore) -> Self {
+                use ff::PrimeField;
+                let wide = (rng.next_u64() as u128) | ((rng.next_u64() as u128) << 64);
+                Self::from_u128(wide % Self::MODULUS_INNER as u128)
+            }
+
+            #[inline(always)]
+            fn double(&self) -> Self {
+                self.double()
+            }
+
+            #[inline(always)]
+            fn square(&self) -> Self {
+                self.square()
+            }
+
+            fn invert(&self) -> subtle::CtOption<Self> {
+                let inverse = ff::Field::pow_vartime(self, [Self::MODULUS_INNER as u64 - 2]);
+                subtle::CtOption::new(inverse, !ff::Field::is_zero(self))
+            }
+
+            fn sqrt(&self) -> subtle::CtOption<Self> {
+                self.sqrt()
+            }
+
+            fn sqrt_ratio(num: &Self, div: &Self) -> (subtle::Choice, Self) {
+                ff::helpers::sqrt_ratio_generic(num, div)
+            }
+        }
+
+        impl ff::PrimeField for $field {
+            const NUM_BITS: u32 = <$inner>::BITS - Self::MODULUS_INNER.leading_zeros();
+            const CAPACITY: u32 = Self::NUM_BITS - 1;
+            const TWO_INV: Self = Self::from_canonical(Self::MODULUS_INNER / 2 + 1);
+            const MULTIPLICATIVE_GENERATOR: Self = Self::from_canonical($mul_gen);
+            const S: u32 = (Self::MODULUS_INNER - 1).trailing_zeros();
+            const ROOT_OF_UNITY: Self = Self::from_canonical($root_of_unity);
+            const ROOT_OF_UNITY_INV: Self = Self::from_canonical($root_of_unity_inv);
+            const DELTA: Self = Self::from_canonical($delta);
+            const MODULUS: &'static str = $modulus;
+
+            type Repr = $crate::serde::Repr<{ $field::SIZE }>;
+
+            fn from_u128(v: u128) -> Self {
+                $field::from_canonical((v % Self::MODULUS_INNER as u128) as $inner)
+            }
+
+            fn from_repr(repr: Self::Repr) -> subtle::CtOption<Self> {
+                let val = <$inner>::from_le_bytes(repr.into());
+                subtle::CtOption::new(
+                    $field::from_canonical(val % Self::MODULUS_INNER),
+                    subtle::Choice::from((val < Self::MODULUS_INNER) as u8),
+                )
+            }
+
+            fn to_repr(&self) -> Self::Repr {
+                self.to_canonical().to_le_bytes().into()
+            }
+
+            fn is_odd(&self) -> subtle::Choice {
+                subtle::Choice::from((self.to_canonical() & 1) as u8)
+            }
+        }
+
+        impl ff::WithSmallOrderMulGroup<3> for $field {
+            const ZETA: Self = Self::from_canonical($zeta);
+        }
+
+        #[cfg(feature = "bits")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "bits")))]
+        impl ff::PrimeFieldBits for $field {
+            type ReprBits = [$inner; 1];
+
+            fn to_le_bits(&self) -> ff::FieldBits<Self::ReprBits> {
+                ff::FieldBits::new([self.to_canonical()])
+            }
+
+            fn char_le_bits() -> ff::FieldBits<Self::ReprBits> {
+                ff::FieldBits::new([Self::MODULUS_INNER])
+            }
+        }
+
+        $(
+            impl ff::FromUniformBytes<{ $from_uniform }> for $field {
+                fn from_uniform_bytes(bytes: &[u8; $from_uniform]) -> Self {
+                    // Horner's rule over the 32-bit limbs of the little-endian integer
+                    let shift = $field::from(1u64 << 32);
+                    bytes.chunks(4).rev().fold($field::zero(), |acc, limb| {
+                        let limb = u32::from_le_bytes(limb.try_into().unwrap());
+                        acc * shift + $field::from(limb as u64)
+                    })
+                }
+            }
+        )*
+
+        impl From<$field> for $crate::serde::Repr<{ $field::SIZE }> {
+            fn from(value: $field) -> $crate::serde::Repr<{ $field::SIZE }> {
+                use ff::PrimeField;
+                value.to_repr()
+            }
+        }
+
+        impl<'a> From<&'a $field> for $crate::serde::Repr<{ $field::SIZE }> {
+            fn from(value: &'a $field) -> $crate::serde::Repr<{ $field::SIZE }> {
+                use ff::PrimeField;
+                value.to_repr()
+            }
+        }
+
+        impl $crate::serde::SerdeObject for $field {
+            fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
+                debug_assert_eq!(bytes.len(), Self::SIZE);
+                $field(<$inner>::from_le_bytes(bytes.try_into().unwrap()))
+            }
+
+            fn from_raw_bytes(bytes: &[u8]) -> Option<Self> {
+                if bytes.len() != Self::SIZE {
+                    return None;
+                }
+                let elt = Self::from_raw_bytes_unchecked(bytes);
+                (elt.0 < Self::MODULUS_INNER).then_some(elt)
+            }
+
+            fn to_raw_bytes(&self) -> Vec<u8> {
+                self.0.to_le_bytes().to_vec()
+            }
+
+            fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
+                let mut buf = [0; $field::SIZE];
+                reader.read_exact(&mut buf).unwrap();
+                $field(<$inner>::from_le_bytes(buf))
+            }
+
+            fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+                let mut buf = [0; $field::SIZE];
+                reader.read_exact(&mut buf)?;
+                let elt = $field(<$inner>::from_le_bytes(buf));
+                (elt.0 < Self::MODULUS_INNER).then_some(elt).ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "input number is not less than field modulus",
+                    )
+                })
+            }
+
+            fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                writer.write_all(&self.0.to_le_bytes())
+            }
+        }
+
+        $crate::impl_binops_calls!($field);
+        $crate::impl_binops_additive!($field, $field);
+        $crate::impl_binops_multiplicative!($field, $field);
+        $crate::impl_sum_prod!($field);
+        $crate::serialize_deserialize_primefield!($field);
+    };
+}
@@ -84,6 +84,8 @@ macro_rules! impl_tower2 {
             };
         }
 
+        $crate::impl_extension_degree!($field, 2);
+
         impl Legendre for $field {
             fn legendre(&self) -> i64 {
                 self.norm().legendre()
@@ -33,6 +33,21 @@ macro_rules! extend_field_legendre {
     };
 }
 
+/// The degree of a field as an extension of its prime subfield.
+pub trait ExtensionDegree {
+    /// One for a prime field.
+    const DEGREE: usize;
+}
+
+#[macro_export]
+macro_rules! impl_extension_degree {
+    ($field:ident, $degree:expr) => {
+        impl $crate::ff_ext::ExtensionDegree for $field {
+            const DEGREE: usize = $degree;
+        }
+    };
+}
+
 pub trait ExtField: ff::Field {
     const NON_RESIDUE: Self;
     #[must_use]
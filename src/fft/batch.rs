@@ -0,0 +1,183 @@
+use ff::Field;
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefMutIterator, ParallelIterator};
+use rayon::slice::ParallelSliceMut;
+
+use super::{
+    bitreverse, bitreversed_fft, fft_with_layout, powers, FftGroup, TwiddleLayout,
+    SIX_STEP_MIN_LOG_N,
+};
+
+/// Smallest number of elements processed by a task of [`best_fft_rows`].
+const MIN_TASK_LEN: usize = 1 << 10;
+
+/// Returns the pairs of indices swapped by the bit-reversal permutation of
+/// size $2^k$, when provided `log_n` = $k$.
+fn bitreverse_swaps(log_n: u32) -> Vec<(usize, usize)> {
+    (0..1 << log_n)
+        .filter_map(|k| {
+            let rk = bitreverse(k, log_n as usize);
+            (k < rk).then_some((k, rk))
+        })
+        .collect()
+}
+
+/// Performs the same Fast-Fourier Transformation as [`super::best_fft`] on
+/// every column of `columns`, all of size $n = 2^k$.
+///
+/// The twiddle factors and the bit-reversal permutation are computed once for
+/// all the columns, and the columns are transformed in parallel, so that many
+/// small transforms keep every thread busy.
+///
+/// This function will panic if a column is not of size $n$.
+///
+/// This will use multithreading if beneficial.
+pub fn best_fft_batch<Scalar: Field, G: FftGroup<Scalar>>(
+    columns: &mut [Vec<G>],
+    omega: Scalar,
+    log_n: u32,
+) {
+    let n = 1 << log_n;
+    for column in columns.iter() {
+        assert_eq!(column.len(), n, "columns of the wrong size");
+    }
+    if n == 1 {
+        return;
+    }
+
+    let twiddles = powers(omega, n / 2);
+    let twiddles = TwiddleLayout::Powers(&twiddles);
+
+    if log_n >= SIX_STEP_MIN_LOG_N {
+        columns
+            .par_iter_mut()
+            .for_each(|column| fft_with_layout(column, log_n, twiddles));
+        return;
+    }
+
+    let swaps = bitreverse_swaps(log_n);
+    columns.par_iter_mut().for_each(|column| {
+        for (k, rk) in swaps.iter() {
+            column.swap(*k, *rk);
+        }
+        bitreversed_fft(column, log_n, twiddles);
+    });
+}
+
+/// Performs the same Fast-Fourier Transformation as [`super::best_fft`] on
+/// every column of the row-major matrix `matrix` of $n = 2^k$ rows of `width`
+/// elements, that is `matrix[i * width + j]` is the $i$-th element of the
+/// $j$-th column.
+///
+/// The butterflies operate on whole rows, so that every twiddle factor is
+/// read once for all the columns and memory is accessed contiguously.
+///
+/// This function will panic if `matrix` is not of size $n \cdot$ `width`.
+///
+/// This will use multithreading if beneficial.
+pub fn best_fft_rows<Scalar: Field, G: FftGroup<Scalar>>(
+    matrix: &mut [G],
+    width: usize,
+    omega: Scalar,
+    log_n: u32,
+) {
+    let n = 1 << log_n;
+    assert_eq!(matrix.len(), n * width, "matrix of the wrong size");
+    if n == 1 || width == 0 {
+        return;
+    }
+
+    // bit-reversal of the rows
+    for (k, rk) in bitreverse_swaps(log_n) {
+        let (lo, hi) = matrix.split_at_mut(rk * width);
+        lo[k * width..(k + 1) * width].swap_with_slice(&mut hi[..width]);
+    }
+
+    let twiddles = powers(omega, n / 2);
+    recursive_row_butterflies(matrix, width, TwiddleLayout::Powers(&twiddles));
+}
+
+fn recursive_row_butterflies<Scalar: Field, G: FftGroup<Scalar>>(
+    a: &mut [G],
+    width: usize,
+    twiddles: TwiddleLayout<Scalar>,
+) {
+    let half = a.len() / width / 2;
+    if half == 0 {
+        return;
+    }
+
+    let (left, right) = a.split_at_mut(half * width);
+    if half > 1 {
+        rayon::join(
+            || recursive_row_butterflies(left, width, twiddles),
+            || recursive_row_butterflies(right, width, twiddles),
+        );
+    }
+
+    let (layer, stride) = twiddles.layer(half);
+    left.par_chunks_mut(width)
+        .zip(right.par_chunks_mut(width))
+        .enumerate()
+        .with_min_len((MIN_TASK_LEN / width).max(1))
+        .for_each(|(i, (left, right))| {
+            for (a, b) in left.iter_mut().zip(right.iter_mut()) {
+                let mut t = *b;
+                // case when twiddle factor is one
+                if i > 0 {
+                    t *= &layer[i * stride];
+                }
+                *b = *a;
+                *a += &t;
+                *b -= &t;
+            }
+        });
+}
+
+#[cfg(test)]
+mod test {
+    use ff::{Field, PrimeField};
+    use rand_core::OsRng;
+
+    use super::{best_fft_batch, best_fft_rows};
+    use crate::bn256::Fr;
+    use crate::fft::best_fft;
+
+    #[test]
+    fn test_best_fft_batch() {
+        for log_n in [0, 1, 2, 5, 10] {
+            let n = 1 << log_n;
+            let omega = Fr::ROOT_OF_UNITY.pow_vartime([1 << (Fr::S - log_n)]);
+
+            for width in [0, 1, 3, 8] {
+                let columns: Vec<Vec<_>> = (0..width)
+                    .map(|_| (0..n).map(|_| Fr::random(OsRng)).collect())
+                    .collect();
+                let mut expected = columns.clone();
+                for column in expected.iter_mut() {
+                    best_fft(column, omega, log_n);
+                }
+
+                let mut batch = columns.clone();
+                best_fft_batch(&mut batch, omega, log_n);
+                assert_eq!(batch, expected);
+
+                // row-major
+                let mut matrix: Vec<_> = (0..n)
+                    .flat_map(|i| columns.iter().map(move |column| column[i]))
+                    .collect();
+                best_fft_rows(&mut matrix, width, omega, log_n);
+                for (j, column) in expected.iter().enumerate() {
+                    for (i, e) in column.iter().enumerate() {
+                        assert_eq!(matrix[i * width + j], *e);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "columns of the wrong size")]
+    fn test_best_fft_batch_wrong_size() {
+        best_fft_batch(&mut [vec![Fr::ONE; 4], vec![Fr::ONE; 2]], Fr::ONE, 2);
+    }
+}
@@ -0,0 +1,285 @@
+use ff::{BatchInvert, PrimeField};
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefMutIterator, ParallelIterator};
+use rayon::slice::{ParallelSlice, ParallelSliceMut};
+
+use super::{best_fft_with_twiddles, distribute_powers, powers, FftGroup, FftTwiddles};
+
+/// The multiplicative subgroup of order $n = 2^k$ of a prime field, together
+/// with the roots of unity, twiddle factors and coset shift needed to move
+/// between coefficient and evaluation form over it.
+///
+/// The transforms are generic over [`FftGroup`], so they apply to vectors of
+/// field elements as well as of curve points.
+#[derive(Clone, Debug)]
+pub struct EvaluationDomain<F: PrimeField> {
+    k: u32,
+    n: usize,
+    omega: F,
+    omega_inv: F,
+    n_inv: F,
+    coset_shift: F,
+    coset_shift_inv: F,
+    twiddles: FftTwiddles<F>,
+    twiddles_inv: FftTwiddles<F>,
+}
+
+impl<F: PrimeField> EvaluationDomain<F> {
+    /// Returns the domain of size $2^k$, with `F::MULTIPLICATIVE_GENERATOR` as
+    /// coset shift.
+    ///
+    /// This function will panic if `k` is larger than `F::S`.
+    pub fn new(k: u32) -> Self {
+        assert!(k <= F::S, "domain larger than the 2-adic subgroup");
+
+        let mut omega = F::ROOT_OF_UNITY;
+        let mut omega_inv = F::ROOT_OF_UNITY_INV;
+        for _ in k..F::S {
+            omega = omega.square();
+            omega_inv = omega_inv.square();
+        }
+
+        let n = 1 << k;
+        let coset_shift = F::MULTIPLICATIVE_GENERATOR;
+
+        Self {
+            k,
+            n,
+            omega,
+            omega_inv,
+            n_inv: F::TWO_INV.pow_vartime([k as u64]),
+            coset_shift,
+            coset_shift_inv: coset_shift.invert().unwrap(),
+            twiddles: FftTwiddles::new(omega, k),
+            twiddles_inv: FftTwiddles::new(omega_inv, k),
+        }
+    }
+
+    /// Uses the coset $\mathit{shift} \cdot H$ in [`Self::coset_fft`] and
+    /// [`Self::coset_ifft`].
+    ///
+    /// This function will panic if `shift` is zero.
+    pub fn with_coset_shift(mut self, shift: F) -> Self {
+        self.coset_shift_inv = Option::from(shift.invert()).expect("coset shift must be nonzero");
+        self.coset_shift = shift;
+        self
+    }
+
+    /// Returns $k$.
+    pub fn k(&self) -> u32 {
+        self.k
+    }
+
+    /// Returns the size $n = 2^k$ of the domain.
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the generator $\omega$ of the domain.
+    pub fn omega(&self) -> F {
+        self.omega
+    }
+
+    /// Returns $\omega^{-1}$.
+    pub fn omega_inv(&self) -> F {
+        self.omega_inv
+    }
+
+    /// Returns $n^{-1}$.
+    pub fn n_inv(&self) -> F {
+        self.n_inv
+    }
+
+    /// Returns the coset shift.
+    pub fn coset_shift(&self) -> F {
+        self.coset_shift
+    }
+
+    /// Returns the element $\omega^i$ of the domain.
+    pub fn element(&self, i: usize) -> F {
+        self.omega.pow_vartime([i as u64])
+    }
+
+    /// Evaluates the vanishing polynomial $Z_H(X) = X^n - 1$ of the domain at
+    /// `x`.
+    pub fn evaluate_vanishing_polynomial(&self, x: F) -> F {
+        x.pow_vartime([self.n as u64]) - F::ONE
+    }
+
+    /// Evaluates at `x` the $n$ Lagrange basis polynomials of the domain, the
+    /// $i$-th of which is one at $\omega^i$ and zero on the rest of the
+    /// domain. Outside of the domain,
+    /// $L_i(x) = \frac{\omega^i Z_H(x)}{n (x - \omega^i)}$.
+    pub fn evaluate_lagrange_basis(&self, x: F) -> Vec<F> {
+        let elements = powers(self.omega, self.n);
+        let z = self.evaluate_vanishing_polynomial(x);
+        if bool::from(z.is_zero()) {
+            return elements
+                .iter()
+                .map(|w| if *w == x { F::ONE } else { F::ZERO })
+                .collect();
+        }
+
+        let mut basis: Vec<_> = elements.iter().map(|w| x - w).collect();
+        let num_threads = rayon::current_num_threads();
+        let chunk = ((self.n + num_threads - 1) / num_threads).max(1);
+        let c = z * self.n_inv;
+        basis
+            .par_chunks_mut(chunk)
+            .zip(elements.par_chunks(chunk))
+            .for_each(|(basis, elements)| {
+                basis.iter_mut().batch_invert();
+                for (l, w) in basis.iter_mut().zip(elements.iter()) {
+                    *l *= c * w;
+                }
+            });
+        basis
+    }
+
+    /// Transforms the coefficients of a polynomial of degree $n - 1$ into its
+    /// evaluations at $\omega^0, \ldots, \omega^{n - 1}$.
+    ///
+    /// This function will panic if `a` is not of size $n$.
+    pub fn fft<G: FftGroup<F>>(&self, a: &mut [G]) {
+        best_fft_with_twiddles(a, &self.twiddles);
+    }
+
+    /// Inverse of [`Self::fft`].
+    ///
+    /// This function will panic if `a` is not of size $n$.
+    pub fn ifft<G: FftGroup<F>>(&self, a: &mut [G]) {
+        best_fft_with_twiddles(a, &self.twiddles_inv);
+        let n_inv = self.n_inv;
+        a.par_iter_mut().for_each(|a| *a *= &n_inv);
+    }
+
+    /// Transforms the coefficients of a polynomial of degree $n - 1$ into its
+    /// evaluations at $g \omega^0, \ldots, g \omega^{n - 1}$, where $g$ is
+    /// the coset shift.
+    ///
+    /// This function will panic if `a` is not of size $n$.
+    pub fn coset_fft<G: FftGroup<F>>(&self, a: &mut [G]) {
+        assert_eq!(a.len(), self.n);
+        distribute_powers(a, F::ONE, self.coset_shift);
+        self.fft(a);
+    }
+
+    /// Inverse of [`Self::coset_fft`].
+    ///
+    /// This function will panic if `a` is not of size $n$.
+    pub fn coset_ifft<G: FftGroup<F>>(&self, a: &mut [G]) {
+        best_fft_with_twiddles(a, &self.twiddles_inv);
+        // the division by n is folded into the coset unshift
+        distribute_powers(a, self.n_inv, self.coset_shift_inv);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ff::{Field, PrimeField};
+    use rand_core::OsRng;
+
+    use super::EvaluationDomain;
+    use crate::bn256::{Fr, G1};
+
+    fn eval(coeffs: &[Fr], x: Fr) -> Fr {
+        coeffs.iter().rev().fold(Fr::ZERO, |acc, c| acc * x + c)
+    }
+
+    #[test]
+    fn test_evaluation_domain() {
+        for k in [0, 1, 2, 5, 10] {
+            let domain = EvaluationDomain::<Fr>::new(k);
+            let n = domain.size();
+            assert_eq!(domain.omega().pow_vartime([n as u64]), Fr::ONE);
+            if k > 0 {
+                assert_eq!(domain.omega().pow_vartime([n as u64 / 2]), -Fr::ONE);
+            }
+            assert_eq!(domain.omega() * domain.omega_inv(), Fr::ONE);
+            assert_eq!(domain.n_inv() * Fr::from(n as u64), Fr::ONE);
+
+            let coeffs: Vec<_> = (0..n).map(|_| Fr::random(OsRng)).collect();
+
+            let mut evals = coeffs.clone();
+            domain.fft(&mut evals);
+            for (i, e) in evals.iter().enumerate() {
+                assert_eq!(*e, eval(&coeffs, domain.omega().pow_vartime([i as u64])));
+            }
+            domain.ifft(&mut evals);
+            assert_eq!(evals, coeffs);
+
+            let mut coset_evals = coeffs.clone();
+            domain.coset_fft(&mut coset_evals);
+            for (i, e) in coset_evals.iter().enumerate() {
+                let x = domain.coset_shift() * domain.omega().pow_vartime([i as u64]);
+                assert_eq!(*e, eval(&coeffs, x));
+            }
+            domain.coset_ifft(&mut coset_evals);
+            assert_eq!(coset_evals, coeffs);
+
+            // other coset
+            let domain = domain.with_coset_shift(Fr::from(7));
+            let mut coset_evals = coeffs.clone();
+            domain.coset_fft(&mut coset_evals);
+            assert_eq!(coset_evals[0], eval(&coeffs, Fr::from(7)));
+            domain.coset_ifft(&mut coset_evals);
+            assert_eq!(coset_evals, coeffs);
+        }
+    }
+
+    #[test]
+    fn test_evaluation_domain_points() {
+        let domain = EvaluationDomain::<Fr>::new(6);
+        let scalars: Vec<_> = (0..domain.size()).map(|_| Fr::random(OsRng)).collect();
+        let points: Vec<_> = scalars.iter().map(|s| G1::generator() * s).collect();
+
+        let mut expected = scalars.clone();
+        domain.coset_fft(&mut expected);
+        let mut evals = points.clone();
+        domain.coset_fft(&mut evals);
+        for (e, s) in evals.iter().zip(expected.iter()) {
+            assert_eq!(*e, G1::generator() * s);
+        }
+
+        domain.coset_ifft(&mut evals);
+        assert_eq!(evals, points);
+    }
+
+    #[test]
+    fn test_lagrange_basis() {
+        for k in [0, 1, 6] {
+            let domain = EvaluationDomain::<Fr>::new(k);
+            let coeffs: Vec<_> = (0..domain.size()).map(|_| Fr::random(OsRng)).collect();
+            let mut evals = coeffs.clone();
+            domain.fft(&mut evals);
+
+            let x = Fr::random(OsRng);
+            let basis = domain.evaluate_lagrange_basis(x);
+            let interpolated = basis
+                .iter()
+                .zip(evals.iter())
+                .fold(Fr::ZERO, |acc, (l, e)| acc + l * e);
+            assert_eq!(interpolated, eval(&coeffs, x));
+            assert_eq!(
+                domain.evaluate_vanishing_polynomial(x),
+                x.pow_vartime([domain.size() as u64]) - Fr::ONE
+            );
+
+            // on the domain
+            let i = domain.size() - 1;
+            let basis = domain.evaluate_lagrange_basis(domain.element(i));
+            for (j, l) in basis.iter().enumerate() {
+                assert_eq!(*l, if i == j { Fr::ONE } else { Fr::ZERO });
+            }
+            assert_eq!(
+                domain.evaluate_vanishing_polynomial(domain.element(i)),
+                Fr::ZERO
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "domain larger than the 2-adic subgroup")]
+    fn test_evaluation_domain_too_large() {
+        EvaluationDomain::<Fr>::new(Fr::S + 1);
+    }
+}
@@ -0,0 +1,735 @@
+use std::collections::HashSet;
+
+use ff::{BatchInvert, PrimeField};
+
+use crate::poly::karatsuba;
+use crate::secp256k1::Fp;
+
+/// Smallest size of the sub-problems solved in parallel.
+const PAR_MIN_SIZE: usize = 1 << 10;
+
+/// A point of a curve in affine coordinates, `None` being the point at
+/// infinity.
+type Point<F> = Option<(F, F)>;
+
+/// The curve $y^2 = x^3 + a x^2 + b x$.
+///
+/// The curves of the domain and of its isogeny chain are only known at run
+/// time, while a [`CurveExt`](crate::CurveExt) of this crate has constant
+/// coefficients and a group of prime order, without the subgroup of order
+/// $2^k$ the domain needs.
+#[derive(Clone, Copy, Debug)]
+struct Curve<F> {
+    a: F,
+    b: F,
+}
+
+impl<F: PrimeField> Curve<F> {
+    fn is_on_curve(&self, (x, y): (F, F)) -> bool {
+        y.square() == x * (x.square() + self.a * x + self.b)
+    }
+
+    fn add(&self, p: Point<F>, q: Point<F>) -> Point<F> {
+        let ((x1, y1), (x2, y2)) = match (p, q) {
+            (None, q) => return q,
+            (p, None) => return p,
+            (Some(p), Some(q)) => (p, q),
+        };
+        let lambda = if x1 == x2 {
+            if bool::from((y1 + y2).is_zero()) {
+                return None;
+            }
+            (x1.square() * F::from(3) + (self.a * x1).double() + self.b)
+                * y1.double().invert().unwrap()
+        } else {
+            (y2 - y1) * (x2 - x1).invert().unwrap()
+        };
+        let x3 = lambda.square() - self.a - x1 - x2;
+        Some((x3, lambda * (x1 - x3) - y1))
+    }
+}
+
+/// The $2$-isogeny of a curve $y^2 = x^3 + a x^2 + b x$ whose kernel is the
+/// point of order two $(x_0, 0)$.
+///
+/// With $s = x - x_0$ the curve is $y^2 = s (s^2 + A s + B)$, and the isogeny
+/// maps $(x, y)$ to $(s + A + B / s, y (1 - B / s^2))$ on the curve
+/// $Y^2 = X^3 - 2 A X^2 + (A^2 - 4 B) X$.
+#[derive(Clone, Copy, Debug)]
+struct Isogeny<F> {
+    x0: F,
+    a: F,
+    b: F,
+}
+
+impl<F: PrimeField> Isogeny<F> {
+    fn new(curve: &Curve<F>, x0: F) -> Self {
+        Self {
+            x0,
+            a: x0 * F::from(3) + curve.a,
+            b: x0.square() * F::from(3) + (curve.a * x0).double() + curve.b,
+        }
+    }
+
+    fn codomain(&self) -> Curve<F> {
+        Curve {
+            a: -self.a.double(),
+            b: self.a.square() - self.b.double().double(),
+        }
+    }
+
+    /// The map on the $x$-coordinates, $\psi(x) = u(x) / v(x)$ with
+    /// $v(x) = x - x_0$.
+    fn map_x(&self, x: F) -> F {
+        let s = x - self.x0;
+        s + self.a + self.b * s.invert().unwrap()
+    }
+
+    fn map(&self, p: Point<F>) -> Point<F> {
+        p.map(|(x, y)| {
+            let s_inv = (x - self.x0).invert().unwrap();
+            (self.map_x(x), y * (F::ONE - self.b * s_inv.square()))
+        })
+    }
+}
+
+/// One half $S$ of a domain whose points are paired by a rational map
+/// $\psi = u / v$ of degree two: the $t$-th and $(t + h)$-th points, for $h$
+/// half the size of $S$, have the same image, the $t$-th point of $\psi(S)$.
+///
+/// A polynomial $P$ of degree less than $2 h$ is uniquely written
+/// $P(X) = (P_0(\psi(X)) + X P_1(\psi(X))) v(X)^{h - 1}$ with $P_0$, $P_1$ of
+/// degree less than $h$, and the evaluations of $P$ on $S$ and of
+/// $(P_0, P_1)$ on $\psi(S)$ are related by $2 \times 2$ linear maps.
+#[derive(Clone, Debug)]
+struct Half<F> {
+    points: Vec<F>,
+    /// $v(s)^{h - 1}$ for every point $s$.
+    vd: Vec<F>,
+    /// $v(s)^{1 - h}$ for every point $s$.
+    vd_inv: Vec<F>,
+    /// $1 / (s_{t + h} - s_t)$ for $t < h$.
+    diff_inv: Vec<F>,
+}
+
+impl<F: PrimeField> Half<F> {
+    fn new(points: Vec<F>, x0: F) -> Self {
+        let h = points.len() / 2;
+        let vd: Vec<_> = points
+            .iter()
+            .map(|x| (*x - x0).pow_vartime([h as u64 - 1]))
+            .collect();
+        let mut vd_inv = vd.clone();
+        vd_inv.iter_mut().batch_invert();
+        let mut diff_inv: Vec<_> = (0..h).map(|t| points[t + h] - points[t]).collect();
+        diff_inv.iter_mut().batch_invert();
+        Self {
+            points,
+            vd,
+            vd_inv,
+            diff_inv,
+        }
+    }
+
+    /// Returns the evaluations of $P_0$ and $P_1$ on $\psi(S)$ given the
+    /// evaluations of $P$ on $S$.
+    fn decompose(&self, evals: &[F]) -> (Vec<F>, Vec<F>) {
+        let h = evals.len() / 2;
+        (0..h)
+            .map(|t| {
+                let y0 = evals[t] * self.vd_inv[t];
+                let y1 = evals[t + h] * self.vd_inv[t + h];
+                let p1 = (y1 - y0) * self.diff_inv[t];
+                (y0 - self.points[t] * p1, p1)
+            })
+            .unzip()
+    }
+
+    /// Returns the evaluations of $P$ on $S$ given the evaluations of $P_0$
+    /// and $P_1$ on $\psi(S)$.
+    fn recompose(&self, p0: &[F], p1: &[F]) -> Vec<F> {
+        let h = p0.len();
+        (0..2 * h)
+            .map(|t| self.vd[t] * (p0[t % h] + self.points[t] * p1[t % h]))
+            .collect()
+    }
+}
+
+/// The even and odd halves of a domain $L_{i, j}$.
+#[derive(Clone, Debug)]
+struct ExtendLayer<F> {
+    even: Half<F>,
+    odd: Half<F>,
+}
+
+/// The precomputed values for the conversions between coefficients and
+/// evaluations on the domain $L_{0, j}$ of size $N$, of even half $E$ and odd
+/// half $O$. $Z_E$ is the vanishing polynomial of $E$.
+#[derive(Clone, Debug)]
+struct Level<F> {
+    /// $e^{N / 2}$ for every point $e$ of $E$.
+    z_even: Vec<F>,
+    /// $e^{-N / 2}$ for every point $e$ of $E$.
+    z_even_inv: Vec<F>,
+    /// $o^{N / 2}$ for every point $o$ of $O$.
+    z_odd: Vec<F>,
+    /// $1 / Z_E(o)$ for every point $o$ of $O$.
+    vanishing_odd_inv: Vec<F>,
+    /// $Z_E^2 \bmod X^{N / 2}$ on $E$.
+    c_even: Vec<F>,
+    /// $Z_E^2 \bmod X^{N / 2}$ on $O$.
+    c_odd: Vec<F>,
+}
+
+/// An evaluation domain of size $n = 2^k$ for the Elliptic Curve Fast-Fourier
+/// Transform (ECFFT) of Ben-Sasson, Carmon, Kopparty and Levit, which brings
+/// the FFT algorithms to fields without large multiplicative subgroups of
+/// order a power of two, such as the base field of secp256k1.
+///
+/// The domain is the set $L$ of the $x$-coordinates of a coset $P + G$ of a
+/// cyclic subgroup $G = \langle g \rangle$ of order $n$ of the points of a
+/// curve $y^2 = x^3 + a x^2 + b x$, with the $m$-th point of $L$ the
+/// $x$-coordinate of $P + m g$. The $2$-isogeny whose kernel is the point of
+/// order two of $G$ maps $L$ two-to-one onto the domain of size $n / 2$ of the
+/// next curve, and iterating gives a chain of $k - 1$ isogenies whose maps
+/// $\psi_i$ on the $x$-coordinates are rational functions of degree two. They
+/// play the role of the squaring in the FFT:
+///
+/// - [`Self::extend`] computes the evaluations of a polynomial of degree less
+///   than $n / 2$ on the odd half of $L$ from its evaluations on the even half
+///   in $O(n \log n)$;
+/// - [`Self::evaluate`] computes the evaluations on $L$ of a polynomial of
+///   degree less than $n$ given by its coefficients in $O(n \log^2 n)$;
+/// - [`Self::interpolate`] computes the coefficients of a polynomial of degree
+///   less than $n$ given by its evaluations on $L$ in $O(n \log^2 n)$.
+///
+/// The last one reduces modulo $X^{n / 2}$ with two reductions à la Montgomery
+/// by the vanishing polynomial of the even half. Building the domain involves
+/// one multiplication of polynomials of size $n / 2$, with Karatsuba's method.
+///
+/// None of the curves of this crate has a subgroup of order $2^k$, so the
+/// domain is built on a curve given by its coefficients: see
+/// [`Self::secp256k1`] for domains of size up to $2^{20}$ over the base field
+/// of secp256k1, and [`Self::new`] for other curves.
+#[derive(Clone, Debug)]
+pub struct EcFftDomain<F: PrimeField> {
+    log_n: u32,
+    points: Vec<F>,
+    /// `extend[j][i]` is for the domain $L_{i, j}$ of the $x$-coordinates of
+    /// $P_i + 2^j m g_i$, where $P_i$ and $g_i$ are the images of $P$ and $g$
+    /// by the first $i$ isogenies.
+    extend: Vec<Vec<ExtendLayer<F>>>,
+    /// `levels[j]` is for the domain $L_{0, j}$.
+    levels: Vec<Level<F>>,
+}
+
+impl<F: PrimeField> EcFftDomain<F> {
+    /// Returns the domain of size $n = 2^k$, when provided `log_n` = $k$, of
+    /// the curve $y^2 = x^3 + a x^2 + b x$, for the subgroup generated by
+    /// `generator` and the coset of `offset`.
+    ///
+    /// Building the domain costs much more than the transforms on it, as it
+    /// multiplies polynomials of size $n / 2$ and converts between
+    /// coefficients and evaluations on every level: over ten minutes on a
+    /// single core for $n = 2^{20}$, so domains should be built once and
+    /// reused.
+    ///
+    /// This function will panic if `log_n` is zero, if the curve is singular,
+    /// if the points are not on the curve, if `generator` is not of order $n$
+    /// or if the $n$ points `offset + m * generator` do not have distinct
+    /// $x$-coordinates, which is the case if `offset` is in the subgroup
+    /// generated by `generator` or in the coset of its point of order two.
+    pub fn new(a: F, b: F, generator: (F, F), offset: (F, F), log_n: u32) -> Self {
+        assert!(log_n > 0, "empty domain");
+        let curve = Curve { a, b };
+        assert!(
+            !bool::from(b.is_zero()) && a.square() != b.double().double(),
+            "singular curve"
+        );
+        assert!(
+            curve.is_on_curve(generator) && curve.is_on_curve(offset),
+            "point not on the curve"
+        );
+
+        // g is of order n if [n / 2] g is of order two, that is has y = 0
+        let mut half_order = Some(generator);
+        for _ in 1..log_n {
+            half_order = curve.add(half_order, half_order);
+        }
+        assert!(
+            half_order.map_or(false, |(_, y)| bool::from(y.is_zero())),
+            "generator not of order 2^log_n"
+        );
+
+        let n = 1 << log_n;
+        let mut points = Vec::with_capacity(n);
+        let mut p = Some(offset);
+        for _ in 0..n {
+            let (x, _) = p.expect("x-coordinates of the coset are not distinct");
+            points.push(x);
+            p = curve.add(p, Some(generator));
+        }
+        let distinct: HashSet<_> = points
+            .iter()
+            .map(|x| x.to_repr().as_ref().to_vec())
+            .collect();
+        assert_eq!(
+            distinct.len(),
+            n,
+            "x-coordinates of the coset are not distinct"
+        );
+
+        // the isogeny chain, with `domains[i]` the x-coordinates of P_i + m g_i
+        let mut domains = vec![points.clone()];
+        let mut kernels = vec![];
+        let (mut curve, mut g) = (curve, Some(generator));
+        for i in 0..log_n - 1 {
+            let mut kernel = g;
+            for _ in i + 1..log_n {
+                kernel = curve.add(kernel, kernel);
+            }
+            let (x0, _) = kernel.unwrap();
+            let isogeny = Isogeny::new(&curve, x0);
+
+            let domain = &domains[i as usize];
+            let next = domain[..domain.len() / 2]
+                .iter()
+                .map(|x| isogeny.map_x(*x))
+                .collect();
+            domains.push(next);
+            kernels.push(x0);
+            g = isogeny.map(g);
+            curve = isogeny.codomain();
+        }
+
+        let half = |domain: &[F], step: usize, parity: usize| -> Vec<F> {
+            domain
+                .iter()
+                .skip(parity * step)
+                .step_by(2 * step)
+                .copied()
+                .collect()
+        };
+
+        // the halves of L_{i, j} are of size 2^(k - i - j - 1), and are split
+        // by the isogenies as long as they have two points or more
+        let log_n = log_n as usize;
+        let extend = (0..log_n - 1)
+            .map(|j| {
+                (0..log_n - 1 - j)
+                    .map(|i| ExtendLayer {
+                        even: Half::new(half(&domains[i], 1 << j, 0), kernels[i]),
+                        odd: Half::new(half(&domains[i], 1 << j, 1), kernels[i]),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let levels = (0..log_n)
+            .map(|j| {
+                let exp = [1 << (log_n - j - 1)];
+                let z_even: Vec<_> = half(&points, 1 << j, 0)
+                    .iter()
+                    .map(|e| e.pow_vartime(exp))
+                    .collect();
+                let mut z_even_inv = z_even.clone();
+                z_even_inv.iter_mut().batch_invert();
+                let z_odd = half(&points, 1 << j, 1)
+                    .iter()
+                    .map(|o| o.pow_vartime(exp))
+                    .collect();
+                Level {
+                    z_even,
+                    z_even_inv,
+                    z_odd,
+                    vanishing_odd_inv: vec![],
+                    c_even: vec![],
+                    c_odd: vec![],
+                }
+            })
+            .collect();
+
+        let mut domain = Self {
+            log_n: log_n as u32,
+            points,
+            extend,
+            levels,
+        };
+
+        // the reductions on L_{0, j} interpolate on L_{0, j + 1}
+        for j in (0..log_n).rev() {
+            let size = 1 << (log_n - j);
+
+            // Z_E - X^(N / 2) is of degree less than N / 2 and is -e^(N / 2)
+            // on E
+            let z_even_neg: Vec<_> = domain.levels[j].z_even.iter().map(|z| -*z).collect();
+            let mut r = domain.exit(j + 1, &z_even_neg);
+
+            let mut c = vec![F::ZERO; 2 * r.len() - 1];
+            karatsuba(&mut c, &r, &r);
+            c.truncate(size / 2);
+            c.resize(size, F::ZERO);
+            let c = domain.enter(j, &c);
+
+            r.resize(size, F::ZERO);
+            let r = domain.enter(j, &r);
+            let level = &mut domain.levels[j];
+            level.vanishing_odd_inv = r
+                .iter()
+                .skip(1)
+                .step_by(2)
+                .zip(level.z_odd.iter())
+                .map(|(r, z)| *r + z)
+                .collect();
+            level.vanishing_odd_inv.iter_mut().batch_invert();
+            level.c_even = c.iter().step_by(2).copied().collect();
+            level.c_odd = c.iter().skip(1).step_by(2).copied().collect();
+        }
+
+        domain
+    }
+
+    /// Returns $k$ such that the size of the domain is $2^k$.
+    pub fn log_n(&self) -> u32 {
+        self.log_n
+    }
+
+    /// Returns the size $n$ of the domain.
+    pub fn size(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns the points of the domain.
+    pub fn points(&self) -> &[F] {
+        &self.points
+    }
+
+    /// Given the evaluations of a polynomial of degree less than $n / 2$ on
+    /// the points of even index of the domain, returns its evaluations on the
+    /// points of odd index.
+    ///
+    /// This function will panic if `evals` is not of size $n / 2$.
+    ///
+    /// This will use multithreading if beneficial.
+    pub fn extend(&self, evals: &[F]) -> Vec<F> {
+        assert_eq!(
+            evals.len(),
+            self.size() / 2,
+            "evaluations of the wrong size"
+        );
+        self.extend_rec(0, 0, evals, false)
+    }
+
+    /// Returns the evaluations on the domain of the polynomial of
+    /// coefficients `coeffs`, lowest degree first.
+    ///
+    /// This function will panic if there are more than $n$ coefficients.
+    ///
+    /// This will use multithreading if beneficial.
+    pub fn evaluate(&self, coeffs: &[F]) -> Vec<F> {
+        assert!(
+            coeffs.len() <= self.size(),
+            "polynomial larger than the domain"
+        );
+        let mut coeffs = coeffs.to_vec();
+        coeffs.resize(self.size(), F::ZERO);
+        self.enter(0, &coeffs)
+    }
+
+    /// Returns the $n$ coefficients, lowest degree first, of the polynomial of
+    /// degree less than $n$ with evaluations `evals` on the domain.
+    ///
+    /// This function will panic if `evals` is not of size $n$.
+    ///
+    /// This will use multithreading if beneficial.
+    pub fn interpolate(&self, evals: &[F]) -> Vec<F> {
+        assert_eq!(evals.len(), self.size(), "evaluations of the wrong size");
+        self.exit(0, evals)
+    }
+
+    /// Extends from the even half of $L_{i, j}$ to the odd one, or the other
+    /// way around.
+    fn extend_rec(&self, i: usize, j: usize, evals: &[F], from_odd: bool) -> Vec<F> {
+        if evals.len() == 1 {
+            return evals.to_vec();
+        }
+
+        // the halves of L_{i, j} are mapped onto the halves of L_{i + 1, j}
+        let layer = &self.extend[j][i];
+        let (from, to) = if from_odd {
+            (&layer.odd, &layer.even)
+        } else {
+            (&layer.even, &layer.odd)
+        };
+        let (p0, p1) = from.decompose(evals);
+        let (p0, p1) = join(
+            evals.len(),
+            || self.extend_rec(i + 1, j, &p0, from_odd),
+            || self.extend_rec(i + 1, j, &p1, from_odd),
+        );
+        to.recompose(&p0, &p1)
+    }
+
+    /// Evaluates on $L_{0, j}$ the polynomial $P_{lo} + X^{N / 2} P_{hi}$,
+    /// from the evaluations of both halves on the even half of $L_{0, j}$,
+    /// which is $L_{0, j + 1}$.
+    fn enter(&self, j: usize, coeffs: &[F]) -> Vec<F> {
+        let size = coeffs.len();
+        if size == 1 {
+            return coeffs.to_vec();
+        }
+
+        let (lo, hi) = coeffs.split_at(size / 2);
+        let (lo, hi) = join(size, || self.enter(j + 1, lo), || self.enter(j + 1, hi));
+        let (lo_odd, hi_odd) = join(
+            size,
+            || self.extend_rec(0, j, &lo, false),
+            || self.extend_rec(0, j, &hi, false),
+        );
+
+        let level = &self.levels[j];
+        let mut evals = Vec::with_capacity(size);
+        for t in 0..size / 2 {
+            evals.push(lo[t] + level.z_even[t] * hi[t]);
+            evals.push(lo_odd[t] + level.z_odd[t] * hi_odd[t]);
+        }
+        evals
+    }
+
+    /// Interpolates on $L_{0, j}$: the evaluations of
+    /// $P_{lo} = P \bmod X^{N / 2}$ on the even half follow from two
+    /// reductions, and those of $P_{hi}$ from $P = P_{lo} + X^{N / 2} P_{hi}$.
+    fn exit(&self, j: usize, evals: &[F]) -> Vec<F> {
+        let size = evals.len();
+        if size == 1 {
+            return evals.to_vec();
+        }
+
+        let level = &self.levels[j];
+        let even: Vec<_> = evals.iter().step_by(2).copied().collect();
+        let odd: Vec<_> = evals.iter().skip(1).step_by(2).copied().collect();
+
+        // T = P / Z_E and then T Z_E^2 / Z_E = P modulo X^(N / 2)
+        let (t_even, t_odd) = self.redc(j, &even, &odd);
+        let mul = |a: &[F], b: &[F]| -> Vec<F> { a.iter().zip(b).map(|(a, b)| *a * b).collect() };
+        let (lo, _) = self.redc(j, &mul(&t_even, &level.c_even), &mul(&t_odd, &level.c_odd));
+        let hi: Vec<_> = even
+            .iter()
+            .zip(lo.iter())
+            .zip(level.z_even_inv.iter())
+            .map(|((p, lo), z)| (*p - lo) * z)
+            .collect();
+
+        let (mut lo, hi) = join(size, || self.exit(j + 1, &lo), || self.exit(j + 1, &hi));
+        lo.extend(hi);
+        lo
+    }
+
+    /// Given the evaluations on $L_{0, j}$ of a polynomial $P$ of degree less
+    /// than $N$, returns the evaluations of the polynomial $T$ of degree less
+    /// than $N / 2$ such that $T Z_E = P + M X^{N / 2}$, which is
+    /// $P Z_E^{-1} \bmod X^{N / 2}$.
+    fn redc(&self, j: usize, even: &[F], odd: &[F]) -> (Vec<F>, Vec<F>) {
+        let level = &self.levels[j];
+
+        // M is of degree less than N / 2 and cancels P + M X^(N / 2) on E
+        let m_even: Vec<_> = even
+            .iter()
+            .zip(level.z_even_inv.iter())
+            .map(|(p, z)| -(*p * z))
+            .collect();
+        let m_odd = self.extend_rec(0, j, &m_even, false);
+
+        let t_odd: Vec<_> = (0..odd.len())
+            .map(|t| (odd[t] + m_odd[t] * level.z_odd[t]) * level.vanishing_odd_inv[t])
+            .collect();
+        let t_even = self.extend_rec(0, j, &t_odd, true);
+        (t_even, t_odd)
+    }
+}
+
+/// The parameters of [`EcFftDomain::secp256k1`], found with
+/// `python3 script/ecfft_secp256k1.py 20 1`.
+const SECP256K1_A: Fp = Fp::from_raw([
+    0xb58b6fdb5ff970b6,
+    0x59970abb3ab4d38e,
+    0xbaad550df5bbfab0,
+    0x7d013f0536b79d52,
+]);
+const SECP256K1_B: Fp = Fp::from_raw([
+    0x26809a6f3a5c519a,
+    0x469793234e8f3deb,
+    0xdaf6deee1e7c9d4d,
+    0xd37df1bbaf3a6c38,
+]);
+const SECP256K1_GENERATOR_X: Fp = Fp::from_raw([
+    0xe62af5897d138bf4,
+    0x82f99af9b58e7f42,
+    0x74d0338332a463ab,
+    0x5202bc03d4233215,
+]);
+const SECP256K1_GENERATOR_Y: Fp = Fp::from_raw([
+    0x4ec340d50d9603ca,
+    0xbba74273ae9f34e7,
+    0x67cff30fd1e55102,
+    0x38e4b2f4fed859cb,
+]);
+const SECP256K1_OFFSET_X: Fp = Fp::from_raw([
+    0xe1fe8a6c39906d84,
+    0x0b1d8709edd130aa,
+    0x5aecfcad0b1a5c2a,
+    0xb4d8e8a2e99778e7,
+]);
+const SECP256K1_OFFSET_Y: Fp = Fp::from_raw([
+    0x35c2ae994346819a,
+    0x627e5f5ace2d63be,
+    0xbf11f703572208b8,
+    0xe6c9d4ed8c7fb84c,
+]);
+
+impl EcFftDomain<Fp> {
+    /// The largest $k$ accepted by [`Self::secp256k1`].
+    pub const SECP256K1_MAX_LOG_N: u32 = 20;
+
+    /// Returns a domain of size $2^k$ over the base field of secp256k1, when
+    /// provided `log_n` = $k$.
+    ///
+    /// secp256k1 itself is of prime order: the domain is built on another
+    /// curve over the same field, with a cyclic subgroup of order
+    /// $2^{20}$. Larger domains need another curve, which can be searched
+    /// with `script/ecfft_secp256k1.py` and passed to [`Self::new`].
+    ///
+    /// See [`Self::new`] for the cost of building a domain, over ten minutes on
+    /// a single core for the largest one.
+    ///
+    /// This function will panic if `log_n` is zero or larger than
+    /// [`Self::SECP256K1_MAX_LOG_N`].
+    pub fn secp256k1(log_n: u32) -> Self {
+        assert!(
+            log_n <= Self::SECP256K1_MAX_LOG_N,
+            "domain larger than the subgroup of the curve"
+        );
+        let mut generator = Some((SECP256K1_GENERATOR_X, SECP256K1_GENERATOR_Y));
+        let curve = Curve {
+            a: SECP256K1_A,
+            b: SECP256K1_B,
+        };
+        for _ in log_n..Self::SECP256K1_MAX_LOG_N {
+            generator = curve.add(generator, generator);
+        }
+        Self::new(
+            SECP256K1_A,
+            SECP256K1_B,
+            generator.unwrap(),
+            (SECP256K1_OFFSET_X, SECP256K1_OFFSET_Y),
+            log_n,
+        )
+    }
+}
+
+/// Runs both closures, in parallel if `size` is large enough.
+fn join<A: Send, B: Send>(
+    size: usize,
+    a: impl FnOnce() -> A + Send,
+    b: impl FnOnce() -> B + Send,
+) -> (A, B) {
+    if size >= PAR_MIN_SIZE {
+        rayon::join(a, b)
+    } else {
+        (a(), b())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ff::Field;
+    use rand_core::OsRng;
+
+    use super::{
+        Curve, EcFftDomain, Fp, SECP256K1_A, SECP256K1_B, SECP256K1_GENERATOR_X,
+        SECP256K1_GENERATOR_Y, SECP256K1_OFFSET_X, SECP256K1_OFFSET_Y,
+    };
+
+    fn horner(coeffs: &[Fp], x: Fp) -> Fp {
+        coeffs.iter().rev().fold(Fp::ZERO, |acc, c| acc * x + c)
+    }
+
+    #[test]
+    fn test_ecfft() {
+        for log_n in [1, 2, 3, 6, 9] {
+            let domain = EcFftDomain::secp256k1(log_n);
+            let n = domain.size();
+            assert_eq!(n, 1 << log_n);
+
+            let coeffs: Vec<_> = (0..n).map(|_| Fp::random(OsRng)).collect();
+            let evals = domain.evaluate(&coeffs);
+            for (x, e) in domain.points().iter().zip(evals.iter()) {
+                assert_eq!(horner(&coeffs, *x), *e);
+            }
+            assert_eq!(domain.interpolate(&evals), coeffs);
+
+            // fewer coefficients
+            let evals = domain.evaluate(&coeffs[..n / 2]);
+            assert_eq!(domain.interpolate(&evals)[..n / 2], coeffs[..n / 2]);
+            assert!(domain.interpolate(&evals)[n / 2..]
+                .iter()
+                .all(|c| bool::from(c.is_zero())));
+
+            // the evaluations on the even points determine the odd ones
+            let even: Vec<_> = evals.iter().step_by(2).copied().collect();
+            let odd: Vec<_> = evals.iter().skip(1).step_by(2).copied().collect();
+            assert_eq!(domain.extend(&even), odd);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "generator not of order 2^log_n")]
+    fn test_ecfft_wrong_order() {
+        let curve = Curve {
+            a: SECP256K1_A,
+            b: SECP256K1_B,
+        };
+        // of order 2^3
+        let mut generator = Some((SECP256K1_GENERATOR_X, SECP256K1_GENERATOR_Y));
+        for _ in 3..EcFftDomain::SECP256K1_MAX_LOG_N {
+            generator = curve.add(generator, generator);
+        }
+        EcFftDomain::new(
+            SECP256K1_A,
+            SECP256K1_B,
+            generator.unwrap(),
+            (SECP256K1_OFFSET_X, SECP256K1_OFFSET_Y),
+            4,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "x-coordinates of the coset are not distinct")]
+    fn test_ecfft_offset_in_subgroup() {
+        let curve = Curve {
+            a: SECP256K1_A,
+            b: SECP256K1_B,
+        };
+        let mut generator = Some((SECP256K1_GENERATOR_X, SECP256K1_GENERATOR_Y));
+        for _ in 4..EcFftDomain::SECP256K1_MAX_LOG_N {
+            generator = curve.add(generator, generator);
+        }
+        let generator = generator.unwrap();
+        EcFftDomain::new(SECP256K1_A, SECP256K1_B, generator, generator, 4);
+    }
+
+    #[test]
+    fn test_ecfft_secp256k1_order() {
+        let curve = Curve {
+            a: SECP256K1_A,
+            b: SECP256K1_B,
+        };
+        // the generator is of order 2^max, its multiple of order two is (0, 0)
+        let mut g = Some((SECP256K1_GENERATOR_X, SECP256K1_GENERATOR_Y));
+        for _ in 1..EcFftDomain::SECP256K1_MAX_LOG_N {
+            g = curve.add(g, g);
+        }
+        assert_eq!(g, Some((Fp::ZERO, Fp::ZERO)));
+        assert_eq!(curve.add(g, g), None);
+    }
+}
@@ -0,0 +1,124 @@
+use ff::PrimeField;
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+use rayon::slice::{ParallelSlice, ParallelSliceMut};
+
+use super::{best_fft, best_fft_rows, FftGroup};
+
+/// Performs a low-degree extension: given the evaluations `evals` of a
+/// polynomial of degree less than $n = 2^k$ on the subgroup of order $n$,
+/// returns its evaluations on the coset $g H$ of the subgroup $H$ of order
+/// $N = 2^{k + b}$, where $b$ is `blowup_log` and $g$ is `coset_shift`. The
+/// $t$-th output is the evaluation at $g \omega_N^t$.
+///
+/// The input is interpolated in place with an inverse FFT of size $n$. The
+/// zero-padded FFT of size $N$ is not run as such: writing $t = j + 2^b i$,
+/// the evaluations at $g \omega_N^j \omega_n^i$ for a fixed $j$ are the FFT of
+/// size $n$ of the coefficients $c_m (g \omega_N^j)^m$. The first $b$ layers
+/// of butterflies, which only see the zero padding and these scalings, are
+/// thus fused into the single pass writing the scaled coefficients, and the
+/// $2^b$ transforms of size $n$ run together on the rows of the output, which
+/// directly is in natural order.
+///
+/// This function will panic if the size of `evals` is not a power of two or if
+/// $N$ is larger than $2^S$, where $S$ is the two-adicity of the field.
+///
+/// This will use multithreading if beneficial.
+pub fn lde<F: PrimeField, G: FftGroup<F>>(
+    mut evals: Vec<G>,
+    blowup_log: u32,
+    coset_shift: F,
+) -> Vec<G> {
+    let n = evals.len();
+    assert!(n.is_power_of_two(), "size is not a power of two");
+    let log_n = n.trailing_zeros();
+    assert!(
+        log_n + blowup_log <= F::S,
+        "domain larger than the 2-adic subgroup"
+    );
+
+    let mut omega = F::ROOT_OF_UNITY;
+    let mut omega_inv = F::ROOT_OF_UNITY_INV;
+    for _ in log_n..F::S {
+        omega_inv = omega_inv.square();
+    }
+    for _ in log_n + blowup_log..F::S {
+        omega = omega.square();
+    }
+    // `omega` is now of order N and `omega_inv` of order n
+
+    // interpolation, the division by n is folded into the scalings below
+    best_fft(&mut evals, omega_inv, log_n);
+    let coeffs = evals;
+    let n_inv = F::TWO_INV.pow_vartime([log_n as u64]);
+
+    // `out[m * 2^b + j]` is `c_m (g omega^j)^m / n`
+    let width = 1 << blowup_log;
+    let mut out = vec![coeffs[0]; n << blowup_log];
+    let num_threads = rayon::current_num_threads();
+    let chunk = ((n + num_threads - 1) / num_threads).max(1);
+    out.par_chunks_mut(chunk * width)
+        .zip(coeffs.par_chunks(chunk))
+        .enumerate()
+        .for_each(|(c, (out, coeffs))| {
+            let m0 = (c * chunk) as u64;
+            // `g^m / n` and `omega^m`
+            let mut scale = n_inv * coset_shift.pow_vartime([m0]);
+            let mut omega_m = omega.pow_vartime([m0]);
+            for (row, coeff) in out.chunks_mut(width).zip(coeffs.iter()) {
+                let mut w = scale;
+                for x in row.iter_mut() {
+                    *x = *coeff;
+                    *x *= &w;
+                    w *= &omega_m;
+                }
+                scale *= &coset_shift;
+                omega_m *= &omega;
+            }
+        });
+
+    best_fft_rows(&mut out, width, omega.pow_vartime([width as u64]), log_n);
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use ff::{Field, PrimeField};
+    use rand_core::OsRng;
+
+    use super::lde;
+    use crate::bn256::{Fr, G1};
+    use crate::fft::EvaluationDomain;
+
+    #[test]
+    fn test_lde() {
+        for log_n in [0, 1, 4, 9] {
+            for blowup_log in [0, 1, 2, 3] {
+                let small = EvaluationDomain::<Fr>::new(log_n);
+                let shift = Fr::random(OsRng);
+                let large = EvaluationDomain::<Fr>::new(log_n + blowup_log).with_coset_shift(shift);
+
+                let evals: Vec<_> = (0..1 << log_n).map(|_| Fr::random(OsRng)).collect();
+                let mut expected = evals.clone();
+                small.ifft(&mut expected);
+                expected.resize(large.size(), Fr::ZERO);
+                large.coset_fft(&mut expected);
+
+                assert_eq!(lde(evals, blowup_log, shift), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lde_points() {
+        let (log_n, blowup_log) = (5, 2);
+        let shift = Fr::MULTIPLICATIVE_GENERATOR;
+        let scalars: Vec<_> = (0..1 << log_n).map(|_| Fr::random(OsRng)).collect();
+        let points: Vec<_> = scalars.iter().map(|s| G1::generator() * s).collect();
+
+        let expected = lde(scalars, blowup_log, shift);
+        let extended = lde(points, blowup_log, shift);
+        for (p, e) in extended.iter().zip(expected.iter()) {
+            assert_eq!(*p, G1::generator() * e);
+        }
+    }
+}
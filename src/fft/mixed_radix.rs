@@ -0,0 +1,291 @@
+use ff::{Field, PrimeField};
+use num_bigint::BigUint;
+use num_traits::Zero;
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefMutIterator, ParallelIterator};
+use rayon::slice::ParallelSliceMut;
+
+use super::{best_fft, powers, FftGroup};
+
+/// Smallest size of the sub-transforms run in parallel.
+const PAR_MIN_SIZE: usize = 1 << 10;
+
+/// Returns a primitive `order`-th root of unity of `F`, that is a power of
+/// `F::MULTIPLICATIVE_GENERATOR` of multiplicative order `order`, or `None` if
+/// there is none because `order` does not divide $p - 1$.
+///
+/// This function will panic if `F::MODULUS` is not a hexadecimal string.
+pub fn root_of_unity<F: PrimeField>(order: u64) -> Option<F> {
+    let modulus = F::MODULUS.trim_start_matches("0x");
+    let p_minus_one = BigUint::parse_bytes(modulus.as_bytes(), 16)
+        .expect("PrimeField::MODULUS is a hexadecimal string")
+        - 1u64;
+    if order == 0 || !(&p_minus_one % order).is_zero() {
+        return None;
+    }
+
+    let exp = p_minus_one / order;
+    let root = F::MULTIPLICATIVE_GENERATOR.pow_vartime(exp.to_u64_digits());
+
+    // `root` has order dividing `order`, it is primitive if no proper divisor
+    // order / q, for a prime q, is a multiple of its order
+    let is_primitive = prime_factors(order)
+        .into_iter()
+        .all(|q| root.pow_vartime([order / q]) != F::ONE);
+    is_primitive.then_some(root)
+}
+
+/// Returns the distinct prime factors of `n`.
+fn prime_factors(mut n: u64) -> Vec<u64> {
+    let mut factors = vec![];
+    let mut q = 2;
+    while q <= n / q {
+        if n % q == 0 {
+            factors.push(q);
+            while n % q == 0 {
+                n /= q;
+            }
+        }
+        q += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+/// Performs a mixed-radix Fast-Fourier Transformation (FFT) on a vector of
+/// size $n = 2^a 3^b$, when provided an element of multiplicative order $n$
+/// called `omega` ($\omega$), for example obtained with [`root_of_unity`].
+/// As with [`best_fft`], the vector `a`, interpreted as the coefficients of a
+/// polynomial of degree $n - 1$, is transformed into the evaluations of this
+/// polynomial at the $n$ distinct powers of $\omega$, and the transformation
+/// is inverted by providing $\omega^{-1}$ and dividing the result by $n$.
+///
+/// The factors $3$ are handled with radix-$3$ butterflies in a recursive
+/// decimation-in-time transform. Sizes that are powers of two are delegated to
+/// [`best_fft`].
+///
+/// This function will panic if $n$ is not of the form $2^a 3^b$.
+///
+/// This will use multithreading if beneficial.
+pub fn mixed_radix_fft<Scalar: Field, G: FftGroup<Scalar>>(a: &mut [G], omega: Scalar) {
+    let n = a.len();
+    assert!(n > 0, "empty domain");
+
+    let mut radices = vec![];
+    let mut m = n;
+    while m % 3 == 0 {
+        radices.push(3);
+        m /= 3;
+    }
+    assert!(
+        m.is_power_of_two(),
+        "domain size is not of the form 2^a 3^b"
+    );
+    let log_m = m.trailing_zeros();
+    if radices.is_empty() {
+        best_fft(a, omega, log_m);
+        return;
+    }
+    radices.extend(std::iter::repeat(2).take(log_m as usize));
+
+    // all the powers of omega
+    let omega_powers = powers(omega, n);
+    let input = a.to_vec();
+    recursive_mixed_radix(&input, 1, a, &radices, &omega_powers);
+}
+
+/// Writes into `out` the FFT of `input[0], input[stride], ...` over
+/// $\omega^{N / n}$, where $N$ is the size of `omega_powers` and $n$ the
+/// product of `radices`.
+fn recursive_mixed_radix<Scalar: Field, G: FftGroup<Scalar>>(
+    input: &[G],
+    stride: usize,
+    out: &mut [G],
+    radices: &[usize],
+    omega_powers: &[Scalar],
+) {
+    let n = out.len();
+    if n == 1 {
+        out[0] = input[0];
+        return;
+    }
+
+    let r = radices[0];
+    let m = n / r;
+
+    // sub-transforms of the `r` decimated inputs
+    let sub_fft = |(s, out): (usize, &mut [G])| {
+        recursive_mixed_radix(
+            &input[s * stride..],
+            stride * r,
+            out,
+            &radices[1..],
+            omega_powers,
+        )
+    };
+    if m >= PAR_MIN_SIZE {
+        out.par_chunks_mut(m).enumerate().for_each(sub_fft);
+    } else {
+        out.chunks_mut(m).enumerate().for_each(sub_fft);
+    }
+
+    // the k-th twiddle factor of the i-th sub-transform is omega_n^{i k}
+    let step = omega_powers.len() / n;
+    let twiddle = |i: usize, k: usize| &omega_powers[i * k * step];
+
+    match r {
+        2 => {
+            let (out0, out1) = out.split_at_mut(m);
+            let butterfly = |(k, (x0, x1)): (usize, (&mut G, &mut G))| {
+                let mut y1 = *x1;
+                y1 *= twiddle(1, k);
+                *x1 = *x0 - &y1;
+                *x0 += &y1;
+            };
+            if m >= PAR_MIN_SIZE {
+                out0.par_iter_mut()
+                    .zip(out1.par_iter_mut())
+                    .enumerate()
+                    .for_each(butterfly);
+            } else {
+                out0.iter_mut()
+                    .zip(out1.iter_mut())
+                    .enumerate()
+                    .for_each(butterfly);
+            }
+        }
+        3 => {
+            // primitive third root of unity
+            let omega_3 = twiddle(1, m);
+            let (out0, rest) = out.split_at_mut(m);
+            let (out1, out2) = rest.split_at_mut(m);
+            let butterfly = |(k, ((x0, x1), x2)): (usize, ((&mut G, &mut G), &mut G))| {
+                let y0 = *x0;
+                let mut y1 = *x1;
+                y1 *= twiddle(1, k);
+                let mut y2 = *x2;
+                y2 *= twiddle(2, k);
+
+                // with omega_3^2 = -1 - omega_3 and d = omega_3 (y1 - y2):
+                // X_0 = y0 + y1 + y2, X_1 = y0 - y2 + d, X_2 = y0 - y1 - d
+                let mut d = y1 - &y2;
+                d *= omega_3;
+                *x0 = y0 + &y1 + &y2;
+                *x1 = y0 - &y2 + &d;
+                *x2 = y0 - &y1 - &d;
+            };
+            if m >= PAR_MIN_SIZE {
+                out0.par_iter_mut()
+                    .zip(out1.par_iter_mut())
+                    .zip(out2.par_iter_mut())
+                    .enumerate()
+                    .for_each(butterfly);
+            } else {
+                out0.iter_mut()
+                    .zip(out1.iter_mut())
+                    .zip(out2.iter_mut())
+                    .enumerate()
+                    .for_each(butterfly);
+            }
+        }
+        _ => unreachable!("unsupported radix"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ff::{Field, PrimeField};
+    use rand_core::OsRng;
+
+    use super::{mixed_radix_fft, prime_factors, root_of_unity};
+    use crate::bn256::{Fr, G1};
+
+    fn naive_dft(a: &[Fr], omega: Fr) -> Vec<Fr> {
+        (0..a.len())
+            .map(|k| {
+                let x = omega.pow_vartime([k as u64]);
+                a.iter().rev().fold(Fr::ZERO, |acc, c| acc * x + c)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_prime_factors() {
+        assert_eq!(prime_factors(1), Vec::<u64>::new());
+        assert_eq!(prime_factors(2), vec![2]);
+        assert_eq!(prime_factors(72), vec![2, 3]);
+        assert_eq!(prime_factors(1 << 63), vec![2]);
+        assert_eq!(prime_factors(2 * 3 * 5 * 7 * 49), vec![2, 3, 5, 7]);
+    }
+
+    #[test]
+    fn test_root_of_unity() {
+        fn check<F: PrimeField>(order: u64) {
+            let root = root_of_unity::<F>(order).unwrap();
+            assert_eq!(root.pow_vartime([order]), F::ONE);
+            for q in prime_factors(order) {
+                assert_ne!(root.pow_vartime([order / q]), F::ONE);
+            }
+        }
+
+        check::<Fr>(1);
+        check::<Fr>(3);
+        check::<Fr>(9 << 28);
+        check::<Fr>(1 << Fr::S);
+        assert!(root_of_unity::<Fr>(27).is_none());
+        assert!(root_of_unity::<Fr>(1 << (Fr::S + 1)).is_none());
+        assert!(root_of_unity::<Fr>(0).is_none());
+
+        check::<crate::secp256k1::Fq>(3);
+        check::<crate::secp256k1::Fp>(3);
+        check::<crate::bls12381::Fr>(3);
+    }
+
+    #[test]
+    fn test_mixed_radix_fft() {
+        for n in [1, 2, 3, 6, 9, 12, 18, 36, 64, 72, 9 * 32] {
+            let omega = root_of_unity::<Fr>(n as u64).unwrap();
+            let a: Vec<_> = (0..n).map(|_| Fr::random(OsRng)).collect();
+            let expected = naive_dft(&a, omega);
+
+            let mut b = a.clone();
+            mixed_radix_fft(&mut b, omega);
+            assert_eq!(b, expected);
+
+            // inverse
+            mixed_radix_fft(&mut b, omega.invert().unwrap());
+            let n_inv = Fr::from(n as u64).invert().unwrap();
+            b.iter_mut().for_each(|b| *b *= n_inv);
+            assert_eq!(b, a);
+        }
+
+        // parallel sub-transforms
+        let n = 9 << 11;
+        let omega = root_of_unity::<Fr>(n as u64).unwrap();
+        let a: Vec<_> = (0..n).map(|_| Fr::random(OsRng)).collect();
+        let mut b = a.clone();
+        mixed_radix_fft(&mut b, omega);
+        mixed_radix_fft(&mut b, omega.invert().unwrap());
+        let n_inv = Fr::from(n as u64).invert().unwrap();
+        b.iter_mut().for_each(|b| *b *= n_inv);
+        assert_eq!(b, a);
+
+        // group elements
+        let n = 18;
+        let omega = root_of_unity::<Fr>(n as u64).unwrap();
+        let a: Vec<_> = (0..n).map(|_| Fr::random(OsRng)).collect();
+        let expected = naive_dft(&a, omega);
+        let mut points: Vec<_> = a.iter().map(|a| G1::generator() * a).collect();
+        mixed_radix_fft(&mut points, omega);
+        for (p, e) in points.iter().zip(expected.iter()) {
+            assert_eq!(*p, G1::generator() * e);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "domain size is not of the form 2^a 3^b")]
+    fn test_mixed_radix_fft_wrong_size() {
+        mixed_radix_fft(&mut [Fr::ONE; 10], Fr::ONE);
+    }
+}
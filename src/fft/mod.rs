@@ -0,0 +1,383 @@
+pub use crate::{CurveAffine, CurveExt};
+use ff::Field;
+use group::{GroupOpsOwned, ScalarMulOwned};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+use rayon::slice::ParallelSliceMut;
+
+mod batch;
+mod domain;
+mod ecfft;
+mod lde;
+mod mixed_radix;
+mod six_step;
+mod srs;
+mod twiddles;
+
+pub use batch::{best_fft_batch, best_fft_rows};
+pub use domain::EvaluationDomain;
+pub use ecfft::EcFftDomain;
+pub use lde::lde;
+pub use mixed_radix::{mixed_radix_fft, root_of_unity};
+pub use srs::{best_fft_affine, srs_to_lagrange};
+pub use twiddles::{best_fft_with_twiddles, FftTwiddles};
+
+/// This represents an element of a group with basic operations that can be
+/// performed. This allows an FFT implementation (for example) to operate
+/// generically over either a field or elliptic curve group.
+pub trait FftGroup<Scalar: Field>:
+    Copy + Send + Sync + 'static + GroupOpsOwned + ScalarMulOwned<Scalar>
+{
+}
+
+impl<T, Scalar> FftGroup<Scalar> for T
+where
+    Scalar: Field,
+    T: Copy + Send + Sync + 'static + GroupOpsOwned + ScalarMulOwned<Scalar>,
+{
+}
+
+/// Performs a radix-$2$ Fast-Fourier Transformation (FFT) on a vector of size
+/// $n = 2^k$, when provided `log_n` = $k$ and an element of multiplicative
+/// order $n$ called `omega` ($\omega$). The result is that the vector `a`, when
+/// interpreted as the coefficients of a polynomial of degree $n - 1$, is
+/// transformed into the evaluations of this polynomial at each of the $n$
+/// distinct powers of $\omega$. This transformation is invertible by providing
+/// $\omega^{-1}$ in place of $\omega$ and dividing each resulting field element
+/// by $n$.
+///
+/// This will use multithreading if beneficial.
+pub fn best_fft<Scalar: Field, G: FftGroup<Scalar>>(a: &mut [G], omega: Scalar, log_n: u32) {
+    let n = a.len();
+    assert_eq!(n, 1 << log_n);
+
+    // precompute twiddle factors
+    let twiddles = powers(omega, n / 2);
+
+    fft_with_layout(a, log_n, TwiddleLayout::Powers(&twiddles));
+}
+
+/// Returns the first `len` powers of `omega`.
+fn powers<Scalar: Field>(omega: Scalar, len: usize) -> Vec<Scalar> {
+    (0..len)
+        .scan(Scalar::ONE, |w, _| {
+            let tw = *w;
+            *w *= &omega;
+            Some(tw)
+        })
+        .collect()
+}
+
+fn bitreverse(mut n: usize, l: usize) -> usize {
+    let mut r = 0;
+    for _ in 0..l {
+        r = (r << 1) | (n & 1);
+        n >>= 1;
+    }
+    r
+}
+
+/// Twiddle factors of an FFT of size $n$ over $\omega$. These also are the
+/// twiddle factors of the FFTs of any size $m < n$ over $\omega^{n / m}$.
+#[derive(Clone, Copy, Debug)]
+enum TwiddleLayout<'a, Scalar> {
+    /// The first $n / 2$ powers of $\omega$.
+    Powers(&'a [Scalar]),
+    /// The table of [`FftTwiddles`].
+    Stages(&'a [Scalar]),
+}
+
+impl<'a, Scalar> TwiddleLayout<'a, Scalar> {
+    /// Returns the twiddle factors of the butterflies of size `2 * half`, as a
+    /// slice and a stride: the `i`-th factor is at `i * stride`.
+    fn layer(&self, half: usize) -> (&'a [Scalar], usize) {
+        match *self {
+            TwiddleLayout::Powers(twiddles) => (twiddles, twiddles.len() / half),
+            TwiddleLayout::Stages(table) => (&table[half - 1..2 * half - 1], 1),
+        }
+    }
+
+    /// Returns the size of the FFT.
+    fn n(&self) -> usize {
+        match *self {
+            TwiddleLayout::Powers(twiddles) => 2 * twiddles.len(),
+            TwiddleLayout::Stages(table) => table.len() + 1,
+        }
+    }
+}
+
+/// Smallest size, in log, transformed with [`six_step::six_step_fft`].
+const SIX_STEP_MIN_LOG_N: u32 = 20;
+
+/// Performs the FFT of size $n$ with the twiddle factors of an FFT of size at
+/// least $n$.
+///
+/// The kernel is chosen by size: a radix-$2$ loop when every layer can be
+/// spread over the threads, the six-step algorithm for large sizes and
+/// recursive radix-$4$ butterflies otherwise.
+fn fft_with_layout<Scalar: Field, G: FftGroup<Scalar>>(
+    a: &mut [G],
+    log_n: u32,
+    twiddles: TwiddleLayout<Scalar>,
+) {
+    let n = a.len();
+    assert_eq!(n, 1 << log_n);
+    if n == 1 {
+        return;
+    }
+    debug_assert!(twiddles.n() >= n);
+
+    if log_n >= SIX_STEP_MIN_LOG_N {
+        six_step::six_step_fft(a, log_n, twiddles);
+        return;
+    }
+
+    for k in 0..n {
+        let rk = bitreverse(k, log_n as usize);
+        if k < rk {
+            a.swap(rk, k);
+        }
+    }
+
+    bitreversed_fft(a, log_n, twiddles);
+}
+
+/// Butterflies of the FFT of `a`, whose elements are already in bit-reversed
+/// order.
+fn bitreversed_fft<Scalar: Field, G: FftGroup<Scalar>>(
+    a: &mut [G],
+    log_n: u32,
+    twiddles: TwiddleLayout<Scalar>,
+) {
+    let threads = rayon::current_num_threads();
+    let log_threads = threads.ilog2();
+
+    if log_n <= log_threads {
+        let mut chunk = 2_usize;
+        for _ in 0..log_n {
+            let (layer, stride) = twiddles.layer(chunk / 2);
+            a.chunks_mut(chunk).for_each(|coeffs| {
+                let (left, right) = coeffs.split_at_mut(chunk / 2);
+                butterflies(left, right, layer, stride);
+            });
+            chunk *= 2;
+        }
+    } else {
+        recursive_butterflies(a, twiddles)
+    }
+}
+
+/// Butterflies between `left` and `right`, with the `i`-th twiddle factor at
+/// `twiddles[i * stride]`.
+fn butterflies<Scalar: Field, G: FftGroup<Scalar>>(
+    left: &mut [G],
+    right: &mut [G],
+    twiddles: &[Scalar],
+    stride: usize,
+) {
+    // case when twiddle factor is one
+    let (a, left) = left.split_at_mut(1);
+    let (b, right) = right.split_at_mut(1);
+    let t = b[0];
+    b[0] = a[0];
+    a[0] += &t;
+    b[0] -= &t;
+
+    left.iter_mut()
+        .zip(right.iter_mut())
+        .enumerate()
+        .for_each(|(i, (a, b))| {
+            let mut t = *b;
+            t *= &twiddles[(i + 1) * stride];
+            *b = *a;
+            *a += &t;
+            *b -= &t;
+        });
+}
+
+/// Radix-$4$ butterflies between the quarters of `a`, that is the two
+/// radix-$2$ layers of size `a.len() / 2` and `a.len()` in a single pass.
+fn butterflies_radix4<Scalar: Field, G: FftGroup<Scalar>>(
+    a: &mut [G],
+    twiddles: TwiddleLayout<Scalar>,
+) {
+    let h = a.len() / 4;
+    let (inner, inner_stride) = twiddles.layer(h);
+    let (outer, outer_stride) = twiddles.layer(2 * h);
+
+    let (left, right) = a.split_at_mut(2 * h);
+    let (a0, a1) = left.split_at_mut(h);
+    let (a2, a3) = right.split_at_mut(h);
+    for (j, (((a0, a1), a2), a3)) in a0
+        .iter_mut()
+        .zip(a1.iter_mut())
+        .zip(a2.iter_mut())
+        .zip(a3.iter_mut())
+        .enumerate()
+    {
+        let (mut x1, mut x3) = (*a1, *a3);
+        // case when the twiddle factors of the inner layer are one
+        if j > 0 {
+            x1 *= &inner[j * inner_stride];
+            x3 *= &inner[j * inner_stride];
+        }
+
+        // inner layer
+        let b0 = *a0 + &x1;
+        let b1 = *a0 - &x1;
+        let mut b2 = *a2 + &x3;
+        let mut b3 = *a2 - &x3;
+
+        // outer layer
+        if j > 0 {
+            b2 *= &outer[j * outer_stride];
+        }
+        b3 *= &outer[(j + h) * outer_stride];
+        *a0 = b0 + &b2;
+        *a2 = b0 - &b2;
+        *a1 = b1 + &b3;
+        *a3 = b1 - &b3;
+    }
+}
+
+fn recursive_butterflies<Scalar: Field, G: FftGroup<Scalar>>(
+    a: &mut [G],
+    twiddles: TwiddleLayout<Scalar>,
+) {
+    let n = a.len();
+    if n == 2 {
+        let t = a[1];
+        a[1] = a[0];
+        a[0] += &t;
+        a[1] -= &t;
+    } else {
+        let (left, right) = a.split_at_mut(n / 2);
+        let (q0, q1) = left.split_at_mut(n / 4);
+        let (q2, q3) = right.split_at_mut(n / 4);
+        if n == 4 {
+            // quarters of size one
+        } else {
+            rayon::join(
+                || {
+                    rayon::join(
+                        || recursive_butterflies(q0, twiddles),
+                        || recursive_butterflies(q1, twiddles),
+                    )
+                },
+                || {
+                    rayon::join(
+                        || recursive_butterflies(q2, twiddles),
+                        || recursive_butterflies(q3, twiddles),
+                    )
+                },
+            );
+        }
+
+        butterflies_radix4(a, twiddles);
+    }
+}
+
+/// Multiplies the $i$-th element of `a` by $c \cdot g^i$.
+fn distribute_powers<Scalar: Field, G: FftGroup<Scalar>>(a: &mut [G], c: Scalar, g: Scalar) {
+    let num_threads = rayon::current_num_threads();
+    let chunk = ((a.len() + num_threads - 1) / num_threads).max(1);
+
+    a.par_chunks_mut(chunk).enumerate().for_each(|(i, a)| {
+        let mut acc = c * g.pow_vartime([(i * chunk) as u64]);
+        for a in a.iter_mut() {
+            *a *= &acc;
+            acc *= g;
+        }
+    });
+}
+
+/// This perform recursive butterfly arithmetic
+pub fn recursive_butterfly_arithmetic<Scalar: Field, G: FftGroup<Scalar>>(
+    a: &mut [G],
+    n: usize,
+    twiddle_chunk: usize,
+    twiddles: &[Scalar],
+) {
+    if n == 2 {
+        let t = a[1];
+        a[1] = a[0];
+        a[0] += &t;
+        a[1] -= &t;
+    } else {
+        let (left, right) = a.split_at_mut(n / 2);
+        rayon::join(
+            || recursive_butterfly_arithmetic(left, n / 2, twiddle_chunk * 2, twiddles),
+            || recursive_butterfly_arithmetic(right, n / 2, twiddle_chunk * 2, twiddles),
+        );
+
+        butterflies(left, right, twiddles, twiddle_chunk);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ff::{Field, PrimeField};
+    use group::Group;
+    use rand_core::OsRng;
+
+    use super::{best_fft, bitreverse, powers, recursive_butterfly_arithmetic};
+    use crate::bn256::{Fr, G1};
+    use crate::small_fields::{BabyBear, Goldilocks};
+
+    /// The radix-2 FFT `best_fft` used to run on every size.
+    fn radix2_fft<G: super::FftGroup<Fr>>(a: &mut [G], omega: Fr, log_n: u32) {
+        let n = a.len();
+        for k in 0..n {
+            let rk = bitreverse(k, log_n as usize);
+            if k < rk {
+                a.swap(rk, k);
+            }
+        }
+        if n > 1 {
+            recursive_butterfly_arithmetic(a, n, 1, &powers(omega, n / 2));
+        }
+    }
+
+    #[test]
+    fn test_best_fft() {
+        for log_n in 0..14 {
+            let omega = Fr::ROOT_OF_UNITY.pow_vartime([1 << (Fr::S - log_n)]);
+            let a: Vec<_> = (0..1 << log_n).map(|_| Fr::random(OsRng)).collect();
+
+            let mut expected = a.clone();
+            radix2_fft(&mut expected, omega, log_n);
+            let mut b = a.clone();
+            best_fft(&mut b, omega, log_n);
+            assert_eq!(b, expected);
+        }
+
+        // group elements
+        let log_n = 5;
+        let omega = Fr::ROOT_OF_UNITY.pow_vartime([1 << (Fr::S - log_n)]);
+        let a: Vec<_> = (0..1 << log_n).map(|_| G1::random(OsRng)).collect();
+        let mut expected = a.clone();
+        radix2_fft(&mut expected, omega, log_n);
+        let mut b = a.clone();
+        best_fft(&mut b, omega, log_n);
+        assert_eq!(b, expected);
+    }
+
+    /// Compares `best_fft` with the evaluation of the polynomial at each power
+    /// of `omega`.
+    fn check_best_fft<F: PrimeField>(log_n: u32) {
+        let omega = F::ROOT_OF_UNITY.pow_vartime([1 << (F::S - log_n)]);
+        let a: Vec<_> = (0..1 << log_n).map(|_| F::random(OsRng)).collect();
+        let mut b = a.clone();
+        best_fft(&mut b, omega, log_n);
+        for (i, b) in b.iter().enumerate() {
+            let x = omega.pow_vartime([i as u64]);
+            let eval = a.iter().rev().fold(F::ZERO, |acc, c| acc * x + c);
+            assert_eq!(*b, eval);
+        }
+    }
+
+    #[test]
+    fn test_best_fft_small_fields() {
+        check_best_fft::<Goldilocks>(8);
+        check_best_fft::<BabyBear>(8);
+    }
+}
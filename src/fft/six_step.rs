@@ -0,0 +1,136 @@
+use ff::Field;
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+use rayon::slice::{ParallelSlice, ParallelSliceMut};
+
+use super::{fft_with_layout, FftGroup, TwiddleLayout};
+
+/// Side of the square tiles of [`transpose`].
+const TILE: usize = 16;
+
+/// Writes into `dst` the transpose of the row-major `rows` x `cols` matrix
+/// `src`.
+fn transpose<T: Copy + Send + Sync>(src: &[T], dst: &mut [T], rows: usize, cols: usize) {
+    debug_assert_eq!(src.len(), rows * cols);
+    debug_assert_eq!(dst.len(), rows * cols);
+
+    // every task writes `TILE` rows of `dst`, one tile at a time
+    dst.par_chunks_mut(rows * TILE)
+        .enumerate()
+        .for_each(|(t, band)| {
+            let c0 = t * TILE;
+            let c1 = (c0 + TILE).min(cols);
+            for r0 in (0..rows).step_by(TILE) {
+                for c in c0..c1 {
+                    for r in r0..(r0 + TILE).min(rows) {
+                        band[(c - c0) * rows + r] = src[r * cols + c];
+                    }
+                }
+            }
+        });
+}
+
+/// Performs the FFT of size $n = n_1 n_2$ with the six-step (Bailey)
+/// algorithm, so that the sub-transforms stay in cache for large $n$:
+///
+/// 1. the input, seen as an $n_2 \times n_1$ matrix, is transposed;
+/// 2. the $n_1$ rows of size $n_2$ are transformed over $\omega^{n_1}$;
+/// 3. the entry $(j_1, k_2)$ is multiplied by $\omega^{j_1 k_2}$;
+/// 4. the matrix is transposed;
+/// 5. the $n_2$ rows of size $n_1$ are transformed over $\omega^{n_2}$;
+/// 6. the matrix is transposed.
+pub(super) fn six_step_fft<Scalar: Field, G: FftGroup<Scalar>>(
+    a: &mut [G],
+    log_n: u32,
+    twiddles: TwiddleLayout<Scalar>,
+) {
+    let n = a.len();
+    let log_n1 = log_n / 2;
+    let log_n2 = log_n - log_n1;
+    let (n1, n2) = (1 << log_n1, 1 << log_n2);
+
+    // powers of omega, the n-th root of unity
+    let (omega_powers, stride) = twiddles.layer(n / 2);
+
+    let mut scratch = a.to_vec();
+    transpose(a, &mut scratch, n2, n1);
+
+    scratch
+        .par_chunks_mut(n2)
+        .enumerate()
+        .for_each(|(j1, row)| {
+            fft_with_layout(row, log_n2, twiddles);
+
+            // j1 < n / 2
+            let omega_j1 = omega_powers[j1 * stride];
+            let mut w = omega_j1;
+            for x in row.iter_mut().skip(1) {
+                *x *= &w;
+                w *= &omega_j1;
+            }
+        });
+
+    transpose(&scratch, a, n1, n2);
+
+    a.par_chunks_mut(n1)
+        .for_each(|row| fft_with_layout(row, log_n1, twiddles));
+
+    transpose(a, &mut scratch, n2, n1);
+    a.par_chunks_mut(n1)
+        .zip(scratch.par_chunks(n1))
+        .for_each(|(a, scratch)| a.copy_from_slice(scratch));
+}
+
+#[cfg(test)]
+mod test {
+    use ff::{Field, PrimeField};
+    use rand_core::OsRng;
+
+    use super::{six_step_fft, transpose};
+    use crate::bn256::Fr;
+    use crate::fft::{
+        bitreverse, powers, recursive_butterfly_arithmetic, FftTwiddles, TwiddleLayout,
+    };
+
+    #[test]
+    fn test_transpose() {
+        for (rows, cols) in [(1, 1), (3, 5), (16, 16), (17, 40)] {
+            let src: Vec<_> = (0..rows * cols).collect();
+            let mut dst = vec![0; rows * cols];
+            transpose(&src, &mut dst, rows, cols);
+            for r in 0..rows {
+                for c in 0..cols {
+                    assert_eq!(dst[c * rows + r], src[r * cols + c]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_six_step_fft() {
+        for log_n in 2..12 {
+            let n = 1 << log_n;
+            let omega = Fr::ROOT_OF_UNITY.pow_vartime([1 << (Fr::S - log_n)]);
+            let a: Vec<_> = (0..n).map(|_| Fr::random(OsRng)).collect();
+
+            // radix-2 reference
+            let twiddles = powers(omega, n / 2);
+            let mut expected = a.clone();
+            for k in 0..n {
+                let rk = bitreverse(k, log_n as usize);
+                if k < rk {
+                    expected.swap(rk, k);
+                }
+            }
+            recursive_butterfly_arithmetic(&mut expected, n, 1, &twiddles);
+
+            let mut b = a.clone();
+            six_step_fft(&mut b, log_n, TwiddleLayout::Powers(&twiddles));
+            assert_eq!(b, expected);
+
+            let table = FftTwiddles::new(omega, log_n);
+            let mut b = a.clone();
+            six_step_fft(&mut b, log_n, TwiddleLayout::Stages(&table.table));
+            assert_eq!(b, expected);
+        }
+    }
+}
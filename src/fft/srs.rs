@@ -0,0 +1,188 @@
+use ff::{Field, PrimeField};
+use group::{Curve, Group, WnafBase, WnafScalar};
+use rayon::iter::{
+    IndexedParallelIterator, IntoParallelIterator, IntoParallelRefMutIterator, ParallelIterator,
+};
+use rayon::slice::{ParallelSlice, ParallelSliceMut};
+
+use super::{bitreverse, powers};
+use crate::CurveAffine;
+
+/// Smallest number of butterflies computed by a task of [`best_fft_affine`].
+const MIN_TASK_LEN: usize = 1 << 4;
+
+/// Window of the signed digits of the twiddle factors in [`best_fft_affine`].
+const TWIDDLE_WINDOW: usize = 4;
+
+/// Performs the same Fast-Fourier Transformation as [`super::best_fft`] on a
+/// vector of affine points.
+///
+/// Each layer of butterflies first computes the products $t = w b$ of the
+/// right inputs by their twiddle factors, and then the outputs $a \pm t$ with
+/// mixed additions, all in parallel. The layer is then normalized back to
+/// affine coordinates with one batch inversion per thread.
+///
+/// The twiddle factors are recoded to signed digits of four bits, so a
+/// product costs about one addition for every five doublings instead of
+/// one for every doubling with the constant-time `C * C::Scalar`: the points
+/// of a setup are public. There is no scalar multiplication when the twiddle
+/// factor is one, in particular in the first layer.
+///
+/// This will use multithreading if beneficial.
+pub fn best_fft_affine<C: CurveAffine>(a: &mut [C], omega: C::Scalar, log_n: u32) {
+    fft_affine(a, omega, log_n, None)
+}
+
+/// Returns `scalar * point`, with the variable time wNAF method.
+fn mul_wnaf<C: CurveAffine>(point: &C, scalar: &C::Scalar) -> C::Curve {
+    let base = WnafBase::<C::Curve, TWIDDLE_WINDOW>::new(point.to_curve());
+    &base * &WnafScalar::new(scalar)
+}
+
+/// [`best_fft_affine`], with the outputs multiplied by `scale` if provided.
+///
+/// The scaling is folded into the last layer: its outputs are computed as
+/// $s a \pm (s w) b$, which saves the multiplication of the right inputs.
+fn fft_affine<C: CurveAffine>(a: &mut [C], omega: C::Scalar, log_n: u32, scale: Option<C::Scalar>) {
+    let n = a.len();
+    assert_eq!(n, 1 << log_n);
+    if n == 1 {
+        if let Some(scale) = scale {
+            a[0] = mul_wnaf(&a[0], &scale).to_affine();
+        }
+        return;
+    }
+
+    for k in 0..n {
+        let rk = bitreverse(k, log_n as usize);
+        if k < rk {
+            a.swap(rk, k);
+        }
+    }
+
+    let twiddles = powers(omega, n / 2);
+    let num_threads = rayon::current_num_threads();
+    let chunk = ((n + num_threads - 1) / num_threads).max(1);
+    let mut layer = vec![C::Curve::identity(); n];
+
+    let mut half = 1;
+    while half < n {
+        let stride = n / 2 / half;
+        let scale = if 2 * half == n { scale } else { None };
+
+        // the k-th butterfly is between a[j] and a[j + half], with the twiddle
+        // factor omega^(i * stride)
+        let butterfly = |k: usize| (k / half * 2 * half + k % half, k % half);
+        let products: Vec<(C::Curve, C::Curve)> = (0..n / 2)
+            .into_par_iter()
+            .with_min_len(MIN_TASK_LEN)
+            .map(|k| {
+                let (j, i) = butterfly(k);
+                let (x, b) = (a[j], a[j + half]);
+                match scale {
+                    Some(scale) => (
+                        mul_wnaf(&x, &scale),
+                        mul_wnaf(&b, &(twiddles[i * stride] * scale)),
+                    ),
+                    None if i == 0 => (x.to_curve(), b.to_curve()),
+                    None => (x.to_curve(), mul_wnaf(&b, &twiddles[i * stride])),
+                }
+            })
+            .collect();
+
+        layer
+            .par_iter_mut()
+            .enumerate()
+            .with_min_len(MIN_TASK_LEN)
+            .for_each(|(idx, out)| {
+                let (block, pos) = (idx / (2 * half), idx % (2 * half));
+                let (i, right) = (pos % half, pos >= half);
+                let (x, t) = products[block * half + i];
+                *out = if right { x - t } else { x + t };
+            });
+
+        layer
+            .par_chunks(chunk)
+            .zip(a.par_chunks_mut(chunk))
+            .for_each(|(layer, a)| C::Curve::batch_normalize(layer, a));
+        half *= 2;
+    }
+}
+
+/// Converts a KZG setup from monomial to Lagrange form: given the points
+/// $[\tau^i] G$ for $i < n = 2^k$, returns the points $[L_i(\tau)] G$, where
+/// $L_i$ is the $i$-th Lagrange polynomial of the subgroup of order $n$, equal
+/// to one at $\omega^i$ and to zero at the other elements.
+///
+/// This is the inverse FFT of the setup, with [`best_fft_affine`] and the
+/// division by $n$ folded into its last layer.
+///
+/// This function will panic if the size of the setup is not a power of two or
+/// if it is larger than $2^S$, where $S$ is the two-adicity of the scalar
+/// field.
+///
+/// This will use multithreading if beneficial.
+pub fn srs_to_lagrange<C: CurveAffine>(monomial: &[C]) -> Vec<C> {
+    let n = monomial.len();
+    assert!(n.is_power_of_two(), "size is not a power of two");
+    let log_n = n.trailing_zeros();
+    assert!(
+        log_n <= C::Scalar::S,
+        "domain larger than the 2-adic subgroup"
+    );
+
+    let mut omega_inv = C::Scalar::ROOT_OF_UNITY_INV;
+    for _ in log_n..C::Scalar::S {
+        omega_inv = omega_inv.square();
+    }
+    let n_inv = C::Scalar::TWO_INV.pow_vartime([log_n as u64]);
+
+    let mut lagrange = monomial.to_vec();
+    fft_affine(&mut lagrange, omega_inv, log_n, Some(n_inv));
+    lagrange
+}
+
+#[cfg(test)]
+mod test {
+    use ff::{Field, PrimeField};
+    use group::{prime::PrimeCurveAffine, Curve, Group};
+    use rand_core::OsRng;
+
+    use super::{best_fft_affine, srs_to_lagrange};
+    use crate::bn256::{Fr, G1Affine, G1};
+    use crate::fft::{best_fft, EvaluationDomain};
+
+    #[test]
+    fn test_best_fft_affine() {
+        for log_n in [0, 1, 2, 3, 7] {
+            let n = 1 << log_n;
+            let omega = Fr::ROOT_OF_UNITY.pow_vartime([1 << (Fr::S - log_n)]);
+            let mut points: Vec<_> = (0..n).map(|_| G1::random(OsRng)).collect();
+            let mut affine = vec![G1Affine::identity(); n];
+            G1::batch_normalize(&points, &mut affine);
+
+            best_fft(&mut points, omega, log_n);
+            best_fft_affine(&mut affine, omega, log_n);
+            for (p, a) in points.iter().zip(affine.iter()) {
+                assert_eq!(p.to_affine(), *a);
+            }
+        }
+    }
+
+    #[test]
+    fn test_srs_to_lagrange() {
+        for log_n in [0, 1, 5] {
+            let tau = Fr::random(OsRng);
+            let g = G1Affine::generator();
+            let monomial: Vec<_> = (0..1 << log_n)
+                .map(|i| (g * tau.pow_vartime([i as u64])).to_affine())
+                .collect();
+
+            let lagrange = srs_to_lagrange(&monomial);
+            let basis = EvaluationDomain::<Fr>::new(log_n).evaluate_lagrange_basis(tau);
+            for (p, l) in lagrange.iter().zip(basis.iter()) {
+                assert_eq!(*p, (g * l).to_affine());
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use ff::Field;
+
+use super::{fft_with_layout, powers, FftGroup, TwiddleLayout};
+
+/// Precomputed twiddle factors of the FFT of size $n = 2^k$ over $\omega$,
+/// to be reused across transforms of the same size with
+/// [`best_fft_with_twiddles`].
+///
+/// The factors are stored in the order in which the butterflies read them:
+/// the layer of butterflies of size $2h$ reads the $h$ powers of
+/// $\omega^{n / 2h}$ from the contiguous range `h - 1..2h - 1` of the table.
+/// This takes $n - 1$ field elements instead of the $n / 2$ powers computed
+/// by [`super::best_fft`] on every call, but spares the strided lookups.
+#[derive(Clone, Debug)]
+pub struct FftTwiddles<F> {
+    omega: F,
+    log_n: u32,
+    pub(super) table: Vec<F>,
+}
+
+impl<F: Field> FftTwiddles<F> {
+    /// Precomputes the twiddle factors of the FFT of size $2^k$ over `omega`,
+    /// when provided `log_n` = $k$.
+    pub fn new(omega: F, log_n: u32) -> Self {
+        let n = 1 << log_n;
+        let mut table = vec![F::ZERO; n - 1];
+        if n > 1 {
+            table[n / 2 - 1..].copy_from_slice(&powers(omega, n / 2));
+        }
+
+        // the powers of omega^2 are every other power of omega
+        let mut half = n / 4;
+        while half > 0 {
+            let (lower, upper) = table.split_at_mut(2 * half - 1);
+            for (dst, src) in lower[half - 1..].iter_mut().zip(upper.iter().step_by(2)) {
+                *dst = *src;
+            }
+            half /= 2;
+        }
+
+        Self {
+            omega,
+            log_n,
+            table,
+        }
+    }
+
+    /// Returns $\omega$.
+    pub fn omega(&self) -> F {
+        self.omega
+    }
+
+    /// Returns $k$.
+    pub fn log_n(&self) -> u32 {
+        self.log_n
+    }
+}
+
+/// Performs the same Fast-Fourier Transformation as [`super::best_fft`] over
+/// the $\omega$ of `twiddles`, reading the twiddle factors from `twiddles`
+/// instead of computing them.
+///
+/// This function will panic if `a` is not of the size of `twiddles`.
+///
+/// This will use multithreading if beneficial.
+pub fn best_fft_with_twiddles<Scalar: Field, G: FftGroup<Scalar>>(
+    a: &mut [G],
+    twiddles: &FftTwiddles<Scalar>,
+) {
+    assert_eq!(a.len(), 1 << twiddles.log_n, "twiddles of the wrong size");
+    fft_with_layout(a, twiddles.log_n, TwiddleLayout::Stages(&twiddles.table));
+}
+
+#[cfg(test)]
+mod test {
+    use ff::{Field, PrimeField};
+    use rand_core::OsRng;
+
+    use super::{best_fft_with_twiddles, FftTwiddles};
+    use crate::bn256::Fr;
+    use crate::fft::best_fft;
+
+    #[test]
+    fn test_fft_twiddles() {
+        for log_n in 0..12 {
+            let omega = Fr::ROOT_OF_UNITY.pow_vartime([1 << (Fr::S - log_n)]);
+            let twiddles = FftTwiddles::new(omega, log_n);
+            assert_eq!(twiddles.log_n(), log_n);
+
+            let a: Vec<_> = (0..1 << log_n).map(|_| Fr::random(OsRng)).collect();
+            let mut expected = a.clone();
+            best_fft(&mut expected, omega, log_n);
+
+            let mut b = a.clone();
+            best_fft_with_twiddles(&mut b, &twiddles);
+            assert_eq!(b, expected);
+            // reuse
+            let mut b = a.clone();
+            best_fft_with_twiddles(&mut b, &twiddles);
+            assert_eq!(b, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "twiddles of the wrong size")]
+    fn test_fft_twiddles_wrong_size() {
+        let twiddles = FftTwiddles::new(Fr::ROOT_OF_UNITY, 3);
+        best_fft_with_twiddles(&mut [Fr::ONE; 16], &twiddles);
+    }
+}
@@ -65,6 +65,8 @@ const ENDO_PARAMS_GRUMPKIN: EndoParameters = EndoParameters {
 
 endo!(G1, Fr, ENDO_PARAMS_GRUMPKIN);
 
+crate::impl_curve_msm!(G1Affine, "glv");
+
 impl group::cofactor::CofactorGroup for G1 {
     type Subgroup = G1;
 
@@ -85,11 +87,16 @@ impl G1 {
     const SVDW_Z: Fq = Fq::ONE;
 
     fn default_hash_to_curve_suite() -> crate::hash_to_curve::Suite<Self, sha2::Sha256, 48> {
-        crate::hash_to_curve::Suite::<G1, sha2::Sha256, 48>::new(
-            b"GRUMPKIN_XMD:SHA-256_SVDW_RO_",
-            Self::SVDW_Z,
-            crate::hash_to_curve::Method::SVDW,
-        )
+        crate::hash_to_curve::Suite::grumpkin_svdw(b"GRUMPKIN_XMD:SHA-256_SVDW_RO_")
+    }
+}
+
+impl<D: crate::hash_to_curve::ExpandMessage> crate::hash_to_curve::Suite<G1, D, 48> {
+    /// The Shallue-van de Woestijne suite of Grumpkin, under the domain separation
+    /// tag `domain`, e.g. `GRUMPKIN_XMD:SHA-256_SVDW_RO_` with `D = Sha256`.
+    pub fn grumpkin_svdw(domain: &[u8]) -> Self {
+        Self::new(domain, G1::SVDW_Z, crate::hash_to_curve::Method::SVDW)
+            .expect("Grumpkin SVDW parameters are valid")
     }
 }
 
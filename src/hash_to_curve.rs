@@ -1,25 +1,114 @@
 #![allow(clippy::op_ref)]
 
-use crate::ff_ext::Legendre;
-use digest::{core_api::BlockSizeUser, Digest};
+use std::fmt;
+
+use crate::ff_ext::{ExtensionDegree, Legendre};
+use digest::{core_api::BlockSizeUser, Digest, ExtendableOutput};
 use ff::{Field, FromUniformBytes, PrimeField};
 use pasta_curves::arithmetic::CurveExt;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
+/// The map from field elements to points used by a hash-to-curve suite.
+/// See: <https://www.rfc-editor.org/rfc/rfc9380.html#section-6>
 pub enum Method<C: CurveExt> {
+    /// Simplified Shallue-van de Woestijne-Ulas method, for curves with `AB != 0`
+    /// or, through an isogeny, for curves with `A = 0`.
     SSWU(Iso<C>),
+    /// Shallue-van de Woestijne method, for any curve.
     SVDW,
 }
 
 /// Map the homogeneous coordinates of a point from the isogenous curve to a point in the original curve.
 #[allow(clippy::type_complexity)]
 pub struct Iso<C: CurveExt> {
-    pub(crate) a: C::Base,
-    pub(crate) b: C::Base,
-    pub(crate) map: Box<dyn Fn(C::Base, C::Base, C::Base) -> C>,
+    /// Coefficient `A'` of the isogenous curve.
+    pub a: C::Base,
+    /// Coefficient `B'` of the isogenous curve.
+    pub b: C::Base,
+    /// The isogeny, from homogeneous coordinates `(x, y, z)` of the isogenous curve.
+    pub map: Box<dyn Fn(C::Base, C::Base, C::Base) -> C>,
+}
+
+/// Error returned by [`Suite::new`] for parameters that do not define a secure
+/// hash-to-curve suite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuiteError {
+    /// `L` bytes are too few to reach 128 bits of security.
+    InsecureLength,
+    /// `Z` is a square, while the simplified SWU method needs a non-square.
+    SquareZ,
+    /// `g(Z)` is zero, where `g(x) = x^3 + A x + B`.
+    ZeroGZ,
+    /// `g(x) - Z` has a root, while the simplified SWU method needs it to be
+    /// irreducible.
+    ReducibleGZ,
+    /// `Z` fails another of the criteria of the method.
+    InvalidZ,
+}
+
+impl fmt::Display for SuiteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SuiteError::InsecureLength => write!(f, "L is too short for 128 bits of security"),
+            SuiteError::SquareZ => write!(f, "Z must be a non-square"),
+            SuiteError::ZeroGZ => write!(f, "g(Z) must be nonzero"),
+            SuiteError::ReducibleGZ => write!(f, "g(x) - Z must be irreducible"),
+            SuiteError::InvalidZ => write!(f, "Z is not a valid parameter of the method"),
+        }
+    }
 }
 
-pub struct Suite<C: CurveExt, D: Digest + BlockSizeUser, const L: usize> {
+impl std::error::Error for SuiteError {}
+
+/// The `expand_message` construction used by a hash-to-curve suite.
+/// See: <https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3>
+///
+/// Hashers implementing [`Digest`] use `expand_message_xmd`, extendable-output
+/// functions use `expand_message_xof` through [`ExpandMsgXof`].
+pub trait ExpandMessage {
+    /// Expands `message` into `out_len` uniformly random bytes under the
+    /// domain separation tag `domain_prefix || domain`.
+    fn expand_message(
+        domain_prefix: &[u8],
+        domain: &[u8],
+        message: &[u8],
+        out_len: usize,
+    ) -> Vec<u8>;
+}
+
+impl<D: Digest + BlockSizeUser> ExpandMessage for D {
+    fn expand_message(
+        domain_prefix: &[u8],
+        domain: &[u8],
+        message: &[u8],
+        out_len: usize,
+    ) -> Vec<u8> {
+        expand_message::<D>(domain_prefix, domain, message, out_len)
+    }
+}
+
+/// `expand_message_xof` with the extendable-output function `H`, as in the
+/// `BLS12381G1_XOF:SHAKE-256_SSWU_RO_` suite.
+pub struct ExpandMsgXof<H>(std::marker::PhantomData<H>);
+
+impl<H: ExtendableOutput + Default> ExpandMessage for ExpandMsgXof<H> {
+    fn expand_message(
+        domain_prefix: &[u8],
+        domain: &[u8],
+        message: &[u8],
+        out_len: usize,
+    ) -> Vec<u8> {
+        expand_message_xof::<H>(domain_prefix, domain, message, out_len)
+    }
+}
+
+/// A hash-to-curve suite: a domain separation tag, an `expand_message`
+/// construction `D` producing `L` bytes per field element, and a map to the
+/// curve.
+///
+/// The suites of the curves of this crate are built by named constructors
+/// such as [`Suite::secp256k1_sswu`], which are generic over `D` and the tag.
+pub struct Suite<C: CurveExt, D: ExpandMessage, const L: usize> {
     domain: Vec<u8>,
     map_to_curve: Box<dyn Fn(C::Base) -> C>,
     _marker: std::marker::PhantomData<D>,
@@ -31,10 +120,19 @@ pub(crate) fn expand_message<D: Digest + BlockSizeUser>(
     message: &[u8],
     out_len: usize,
 ) -> Vec<u8> {
-    assert!(
-        domain_prefix.len() + domain.len() < 256,
-        "long dst is not supported yet"
-    );
+    // Oversize DSTs are hashed down to `H("H2C-OVERSIZE-DST-" || DST)`.
+    // See: <https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3.3>
+    let long_dst;
+    let (domain_prefix, domain) = if domain_prefix.len() + domain.len() > 255 {
+        long_dst = D::new()
+            .chain_update(b"H2C-OVERSIZE-DST-")
+            .chain_update(domain_prefix)
+            .chain_update(domain)
+            .finalize();
+        (&[][..], &long_dst[..])
+    } else {
+        (domain_prefix, domain)
+    };
 
     let mut h = D::new();
     h.update(vec![0; D::block_size()]);
@@ -83,27 +181,101 @@ pub(crate) fn expand_message<D: Digest + BlockSizeUser>(
     out
 }
 
+pub(crate) fn expand_message_xof<H: ExtendableOutput + Default>(
+    domain_prefix: &[u8],
+    domain: &[u8],
+    message: &[u8],
+    out_len: usize,
+) -> Vec<u8> {
+    assert!(out_len < 1 << 16, "output is too long");
+
+    // Oversize DSTs are hashed down to `2 * k / 8` bytes, where `k = 128` is
+    // the target security level.
+    // See: <https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3.3>
+    let mut long_dst = [0; 32];
+    let (domain_prefix, domain) = if domain_prefix.len() + domain.len() > 255 {
+        H::default()
+            .chain(b"H2C-OVERSIZE-DST-")
+            .chain(domain_prefix)
+            .chain(domain)
+            .finalize_xof_into(&mut long_dst);
+        (&[][..], &long_dst[..])
+    } else {
+        (domain_prefix, domain)
+    };
+
+    let mut out = vec![0u8; out_len];
+    H::default()
+        .chain(message)
+        .chain([(out_len >> 8) as u8, out_len as u8])
+        .chain(domain_prefix)
+        .chain(domain)
+        .chain([(domain.len() + domain_prefix.len()) as u8])
+        .finalize_xof_into(&mut out);
+    out
+}
+
+/// Hashes `message` to `count` uniformly random elements of `F`, under the
+/// domain separation tag `dst`, with the `expand_message` construction `D`.
+///
+/// Each element is reduced from `L` bytes, which must reach the target
+/// security level: `L = m * ceil((ceil(log2(p)) + k) / 8)` for an extension
+/// of degree `m` of the prime field of order `p`, so 48 for a 256-bit prime
+/// field at 128 bits of security.
+/// See: <https://www.rfc-editor.org/rfc/rfc9380.html#section-5.2>
+pub fn hash_to_field<F: FromUniformBytes<L>, D: ExpandMessage, const L: usize>(
+    dst: &[u8],
+    message: &[u8],
+    count: usize,
+) -> Vec<F> {
+    let out = D::expand_message(&[], dst, message, L * count);
+    out.chunks(L)
+        .map(|out| {
+            // `from_uniform_bytes` takes little-endian bytes
+            let mut out: [u8; L] = out.try_into().unwrap();
+            out.reverse();
+            F::from_uniform_bytes(&out)
+        })
+        .collect()
+}
+
 #[allow(clippy::type_complexity)]
-pub fn hash_to_curve<'a, C, D: Digest + BlockSizeUser + 'a, const L: usize>(
+pub fn hash_to_curve<'a, C, D: ExpandMessage + 'a, const L: usize>(
     domain_prefix: &'a str,
     suite: Suite<C, D, L>,
 ) -> Box<dyn Fn(&[u8]) -> C + 'a>
 where
     C: CurveExt,
-    C::Base: Legendre,
+    C::Base: Legendre + ExtensionDegree,
     C::Base: FromUniformBytes<L>,
 {
     Box::new(move |message| suite.hash_to_curve(domain_prefix, message))
 }
 
-impl<C: CurveExt, D: Digest + BlockSizeUser, const L: usize> Suite<C, D, L>
+impl<C: CurveExt, D: ExpandMessage, const L: usize> Suite<C, D, L>
 where
-    C::Base: Legendre + FromUniformBytes<L>,
+    C::Base: Legendre + ExtensionDegree + FromUniformBytes<L>,
 {
-    pub(crate) fn new(domain: &[u8], z: C::Base, method: Method<C>) -> Self {
-        // Check for the target bits of  security `k`. Currently, the target security is 128 bits.
-        // See: <https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-16.html#section-5.1>
-        assert!((C::Base::NUM_BITS as usize + 128) / 8 <= L);
+    /// Builds the suite hashing under the domain separation tag `domain`,
+    /// with the map `method` of parameter `z`.
+    ///
+    /// The parameters are checked against the criteria of RFC 9380: `L` must
+    /// reach 128 bits of security for elements of the degree given by
+    /// [`ExtensionDegree`], and `z` must be a valid parameter of the method.
+    /// See: <https://www.rfc-editor.org/rfc/rfc9380.html#section-5> and
+    /// <https://www.rfc-editor.org/rfc/rfc9380.html#appendix-H>
+    pub fn new(domain: &[u8], z: C::Base, method: Method<C>) -> Result<Self, SuiteError> {
+        // Check for the target bits of security `k`. Currently, the target security is 128 bits.
+        // Elements of an extension of degree `m` take `m` times the bytes of the prime field.
+        // See: <https://www.rfc-editor.org/rfc/rfc9380.html#section-5.1>
+        if C::Base::DEGREE * ((C::Base::NUM_BITS as usize + 128 + 7) / 8) > L {
+            return Err(SuiteError::InsecureLength);
+        }
+
+        match &method {
+            Method::SSWU(iso) => check_sswu_z::<C>(z, iso.a, iso.b)?,
+            Method::SVDW => check_svdw_z::<C>(z)?,
+        }
 
         let map_to_curve: Box<dyn Fn(C::Base) -> C> = match method {
             Method::SSWU(iso) => {
@@ -120,36 +292,160 @@ where
             }
         };
 
-        Self {
+        Ok(Self {
             map_to_curve,
             domain: domain.to_vec(),
             _marker: std::marker::PhantomData,
-        }
+        })
     }
 
-    pub(crate) fn hash_to_field(&self, domain_prefix: &[u8], message: &[u8]) -> (C::Base, C::Base) {
-        let out = expand_message::<D>(domain_prefix, &self.domain[..], message, L * 2);
+    /// Hashes `message` to `count` field elements, under the domain separation
+    /// tag `domain_prefix || domain`.
+    /// See: <https://www.rfc-editor.org/rfc/rfc9380.html#section-5.2>
+    pub fn hash_to_field(
+        &self,
+        domain_prefix: &[u8],
+        message: &[u8],
+        count: usize,
+    ) -> Vec<C::Base> {
+        let dst = [domain_prefix, &self.domain[..]].concat();
+        hash_to_field::<C::Base, D, L>(&dst, message, count)
+    }
 
-        let u0 = {
-            let mut out = out[0..L].to_vec();
-            out.reverse();
-            let out: [u8; L] = out.try_into().unwrap();
-            C::Base::from_uniform_bytes(&out)
-        };
+    /// Hashes `message` to a point of the curve, as a random oracle (`_RO_`
+    /// suites). The cofactor is not cleared.
+    pub fn hash_to_curve(&self, domain_prefix: &str, message: &[u8]) -> C {
+        let u = self.hash_to_field(domain_prefix.as_bytes(), message, 2);
+        (self.map_to_curve)(u[0]) + (self.map_to_curve)(u[1])
+    }
 
-        let u1 = {
-            let mut out = out[L..L * 2].to_vec();
-            out.reverse();
-            let out: [u8; L] = out.try_into().unwrap();
-            C::Base::from_uniform_bytes(&out)
-        };
+    /// Encodes `message` to a point of the curve with a single map to the
+    /// curve (`_NU_` suites). The output is not uniformly distributed, and
+    /// the cofactor is not cleared.
+    /// See: <https://www.rfc-editor.org/rfc/rfc9380.html#section-3>
+    pub fn encode_to_curve(&self, domain_prefix: &str, message: &[u8]) -> C {
+        let u = self.hash_to_field(domain_prefix.as_bytes(), message, 1);
+        (self.map_to_curve)(u[0])
+    }
+}
+
+/// Checks the criteria on `Z` of the simplified SWU method, for the curve
+/// `y^2 = x^3 + A x + B` the map lands on.
+/// See: <https://www.rfc-editor.org/rfc/rfc9380.html#appendix-H.2>
+fn check_sswu_z<C: CurveExt>(z: C::Base, a: C::Base, b: C::Base) -> Result<(), SuiteError>
+where
+    C::Base: Legendre + ExtensionDegree,
+{
+    let g = |x: C::Base| (x.square() + a) * x + b;
+    if bool::from(z.ct_quadratic_residue()) {
+        return Err(SuiteError::SquareZ);
+    }
+    if bool::from(g(z).is_zero()) {
+        return Err(SuiteError::ZeroGZ);
+    }
+    let valid = !bool::from(a.is_zero() | b.is_zero())
+        && z != -C::Base::ONE
+        && bool::from(g(b * (z * a).invert().unwrap()).ct_quadratic_residue());
+    if !valid {
+        return Err(SuiteError::InvalidZ);
+    }
+    if has_root(a, b - z) {
+        return Err(SuiteError::ReducibleGZ);
+    }
+    Ok(())
+}
+
+/// Returns `true` if the cubic `x^3 + a x + c` has a root in `F`, that is if
+/// it is reducible. The roots are those of its gcd with `x^q - x`, where `q`
+/// is the order of `F`, computed modulo the cubic.
+fn has_root<F: PrimeField + ExtensionDegree>(a: F, c: F) -> bool {
+    // product modulo the cubic, with x^3 = -a x - c
+    let mul = |u: [F; 3], v: [F; 3]| {
+        let mut w = [F::ZERO; 5];
+        for (i, u) in u.iter().enumerate() {
+            for (j, v) in v.iter().enumerate() {
+                w[i + j] += *u * v;
+            }
+        }
+        for k in [4, 3] {
+            let t = w[k];
+            w[k - 2] -= t * a;
+            w[k - 3] -= t * c;
+        }
+        [w[0], w[1], w[2]]
+    };
 
-        (u0, u1)
+    // `MODULUS` is the characteristic p, and q = p^DEGREE
+    let p_bits: Vec<bool> = F::MODULUS
+        .trim_start_matches("0x")
+        .chars()
+        .flat_map(|c| {
+            let digit = c
+                .to_digit(16)
+                .expect("PrimeField::MODULUS is a hexadecimal string");
+            (0..4).rev().map(move |i| (digit >> i) & 1 == 1)
+        })
+        .collect();
+    let mut x_q = [F::ZERO, F::ONE, F::ZERO];
+    for _ in 0..F::DEGREE {
+        let mut acc = [F::ONE, F::ZERO, F::ZERO];
+        for bit in p_bits.iter() {
+            acc = mul(acc, acc);
+            if *bit {
+                acc = mul(acc, x_q);
+            }
+        }
+        x_q = acc;
     }
+    x_q[1] -= F::ONE;
 
-    pub fn hash_to_curve(&self, domain_prefix: &str, message: &[u8]) -> C {
-        let (u0, u1) = self.hash_to_field(domain_prefix.as_bytes(), message);
-        (self.map_to_curve)(u0) + (self.map_to_curve)(u1)
+    // Euclid's algorithm, on polynomials lowest degree first without leading
+    // zeros
+    let trim = |mut u: Vec<F>| {
+        while u.last().map_or(false, |c| bool::from(c.is_zero())) {
+            u.pop();
+        }
+        u
+    };
+    let (mut u, mut v) = (vec![c, a, F::ZERO, F::ONE], trim(x_q.to_vec()));
+    while !v.is_empty() {
+        let lead_inv = v.last().unwrap().invert().unwrap();
+        while u.len() >= v.len() {
+            let t = *u.last().unwrap() * lead_inv;
+            let shift = u.len() - v.len();
+            u[shift..]
+                .iter_mut()
+                .zip(v.iter())
+                .for_each(|(u, v)| *u -= t * v);
+            u.pop();
+            u = trim(u);
+        }
+        std::mem::swap(&mut u, &mut v);
+    }
+    u.len() > 1
+}
+
+/// Checks the criteria on `Z` of the Shallue-van de Woestijne method.
+/// See: <https://www.rfc-editor.org/rfc/rfc9380.html#appendix-H.1>
+fn check_svdw_z<C: CurveExt>(z: C::Base) -> Result<(), SuiteError>
+where
+    C::Base: Legendre,
+{
+    let (a, b) = (C::a(), C::b());
+    let g = |x: C::Base| (x.square() + a) * x + b;
+    let gz = g(z);
+    if bool::from(gz.is_zero()) {
+        return Err(SuiteError::ZeroGZ);
+    }
+    let four = C::Base::from(4);
+    let h = -(C::Base::from(3) * z.square() + four * a) * (four * gz).invert().unwrap();
+    let valid = !bool::from(h.is_zero())
+        && bool::from(h.ct_quadratic_residue())
+        && bool::from(gz.ct_quadratic_residue() | g(-z * C::Base::TWO_INV).ct_quadratic_residue());
+    if valid {
+        Ok(())
+    } else {
+        Err(SuiteError::InvalidZ)
     }
 }
 
@@ -380,20 +676,24 @@ mod test {
     use super::*;
     use sha2::Sha256;
     use sha2::Sha512;
+    use sha3::{Shake128, Shake256};
     use std::marker::PhantomData;
 
     #[test]
     fn test_expand_message() {
         // Test vectors are taken from:
         // https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-16.html#name-expand_message_xmdsha-256
+        // and, for the long DSTs and `expand_message_xof`, from RFC 9380
+        // appendices K.2, K.4, K.5 and K.6:
+        // https://www.rfc-editor.org/rfc/rfc9380.html
 
-        struct Test<D: Digest + BlockSizeUser> {
+        struct Test<D: ExpandMessage> {
             msg: &'static [u8],
             expect: Vec<u8>,
             _marker: PhantomData<D>,
         }
 
-        impl<D: Digest + BlockSizeUser> Test<D> {
+        impl<D: ExpandMessage> Test<D> {
             fn new(msg: &'static [u8], expect: &str) -> Self {
                 Self {
                     msg,
@@ -404,7 +704,7 @@ mod test {
 
             fn run(&self, domain_prefix: &[u8], domain: &[u8]) {
                 let outlen = self.expect.len();
-                let out = expand_message::<D>(domain_prefix, domain, self.msg, outlen);
+                let out = D::expand_message(domain_prefix, domain, self.msg, outlen);
                 assert_eq!(out, self.expect);
             }
         }
@@ -506,5 +806,343 @@ mod test {
         .for_each(|test| {
             test.run(b"QUUX-V01-CS02-with-expander-", b"SHA512-256");
         });
+
+        [
+            // out len 0x20
+            Test::<Sha256>::new(
+                b"",
+                "e8dc0c8b686b7ef2074086fbdd2f30e3f8bfbd3bdf177f73f04b97ce618a3ed3",
+            ),
+            Test::<Sha256>::new(
+                b"abc",
+                "52dbf4f36cf560fca57dedec2ad924ee9c266341d8f3d6afe5171733b16bbb12",
+            ),
+            Test::<Sha256>::new(
+                b"abcdef0123456789",
+                "35387dcf22618f3728e6c686490f8b431f76550b0b2c61cbc1ce7001536f4521",
+            ),
+            Test::<Sha256>::new(
+                b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+                "01b637612bb18e840028be900a833a74414140dde0c4754c198532c3a0ba42bc",
+            ),
+            Test::<Sha256>::new(
+                b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                "20cce7033cabc5460743180be6fa8aac5a103f56d481cf369a8accc0c374431b",
+            ),
+            // out len 0x80
+            Test::<Sha256>::new(
+                b"",
+                "14604d85432c68b757e485c8894db3117992fc57e0e136f71ad987f789a0abc287c47876978e2388a02af86b1e8d1342e5ce4f7aaa07a87321e691f6fba7e0072eecc1218aebb89fb14a0662322d5edbd873f0eb35260145cd4e64f748c5dfe60567e126604bcab1a3ee2dc0778102ae8a5cfd1429ebc0fa6bf1a53c36f55dfc",
+            ),
+            Test::<Sha256>::new(
+                b"abc",
+                "1a30a5e36fbdb87077552b9d18b9f0aee16e80181d5b951d0471d55b66684914aef87dbb3626eaabf5ded8cd0686567e503853e5c84c259ba0efc37f71c839da2129fe81afdaec7fbdc0ccd4c794727a17c0d20ff0ea55e1389d6982d1241cb8d165762dbc39fb0cee4474d2cbbd468a835ae5b2f20e4f959f56ab24cd6fe267",
+            ),
+            Test::<Sha256>::new(
+                b"abcdef0123456789",
+                "d2ecef3635d2397f34a9f86438d772db19ffe9924e28a1caf6f1c8f15603d4028f40891044e5c7e39ebb9b31339979ff33a4249206f67d4a1e7c765410bcd249ad78d407e303675918f20f26ce6d7027ed3774512ef5b00d816e51bfcc96c3539601fa48ef1c07e494bdc37054ba96ecb9dbd666417e3de289d4f424f502a982",
+            ),
+            Test::<Sha256>::new(
+                b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+                "ed6e8c036df90111410431431a232d41a32c86e296c05d426e5f44e75b9a50d335b2412bc6c91e0a6dc131de09c43110d9180d0a70f0d6289cb4e43b05f7ee5e9b3f42a1fad0f31bac6a625b3b5c50e3a83316783b649e5ecc9d3b1d9471cb5024b7ccf40d41d1751a04ca0356548bc6e703fca02ab521b505e8e45600508d32",
+            ),
+            Test::<Sha256>::new(
+                b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                "78b53f2413f3c688f07732c10e5ced29a17c6a16f717179ffbe38d92d6c9ec296502eb9889af83a1928cd162e845b0d3c5424e83280fed3d10cffb2f8431f14e7a23f4c68819d40617589e4c41169d0b56e0e3535be1fd71fbb08bb70c5b5ffed953d6c14bf7618b35fc1f4c4b30538236b4b08c9fbf90462447a8ada60be495",
+            ),
+        ]
+        .iter()
+        .for_each(|test| {
+            let domain = [&b"SHA256-128-long-DST-"[..], &[b'1'; 208]].concat();
+            test.run(b"QUUX-V01-CS02-with-expander-", &domain);
+        });
+
+        [
+            // out len 0x20
+            Test::<ExpandMsgXof<Shake128>>::new(
+                b"",
+                "86518c9cd86581486e9485aa74ab35ba150d1c75c88e26b7043e44e2acd735a2",
+            ),
+            Test::<ExpandMsgXof<Shake128>>::new(
+                b"abc",
+                "8696af52a4d862417c0763556073f47bc9b9ba43c99b505305cb1ec04a9ab468",
+            ),
+            Test::<ExpandMsgXof<Shake128>>::new(
+                b"abcdef0123456789",
+                "912c58deac4821c3509dbefa094df54b34b8f5d01a191d1d3108a2c89077acca",
+            ),
+            Test::<ExpandMsgXof<Shake128>>::new(
+                b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+                "1adbcc448aef2a0cebc71dac9f756b22e51839d348e031e63b33ebb50faeaf3f",
+            ),
+            Test::<ExpandMsgXof<Shake128>>::new(
+                b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                "df3447cc5f3e9a77da10f819218ddf31342c310778e0e4ef72bbaecee786a4fe",
+            ),
+            // out len 0x80
+            Test::<ExpandMsgXof<Shake128>>::new(
+                b"",
+                "7314ff1a155a2fb99a0171dc71b89ab6e3b2b7d59e38e64419b8b6294d03ffee42491f11370261f436220ef787f8f76f5b26bdcd850071920ce023f3ac46847744f4612b8714db8f5db83205b2e625d95afd7d7b4d3094d3bdde815f52850bb41ead9822e08f22cf41d615a303b0d9dde73263c049a7b9898208003a739a2e57",
+            ),
+            Test::<ExpandMsgXof<Shake128>>::new(
+                b"abc",
+                "c952f0c8e529ca8824acc6a4cab0e782fc3648c563ddb00da7399f2ae35654f4860ec671db2356ba7baa55a34a9d7f79197b60ddae6e64768a37d699a78323496db3878c8d64d909d0f8a7de4927dcab0d3dbbc26cb20a49eceb0530b431cdf47bc8c0fa3e0d88f53b318b6739fbed7d7634974f1b5c386d6230c76260d5337a",
+            ),
+            Test::<ExpandMsgXof<Shake128>>::new(
+                b"abcdef0123456789",
+                "19b65ee7afec6ac06a144f2d6134f08eeec185f1a890fe34e68f0e377b7d0312883c048d9b8a1d6ecc3b541cb4987c26f45e0c82691ea299b5e6889bbfe589153016d8131717ba26f07c3c14ffbef1f3eff9752e5b6183f43871a78219a75e7000fbac6a7072e2b83c790a3a5aecd9d14be79f9fd4fb180960a3772e08680495",
+            ),
+            Test::<ExpandMsgXof<Shake128>>::new(
+                b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+                "ca1b56861482b16eae0f4a26212112362fcc2d76dcc80c93c4182ed66c5113fe41733ed68be2942a3487394317f3379856f4822a611735e50528a60e7ade8ec8c71670fec6661e2c59a09ed36386513221688b35dc47e3c3111ee8c67ff49579089d661caa29db1ef10eb6eace575bf3dc9806e7c4016bd50f3c0e2a6481ee6d",
+            ),
+            Test::<ExpandMsgXof<Shake128>>::new(
+                b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                "9d763a5ce58f65c91531b4100c7266d479a5d9777ba761693d052acd37d149e7ac91c796a10b919cd74a591a1e38719fb91b7203e2af31eac3bff7ead2c195af7d88b8bc0a8adf3d1e90ab9bed6ddc2b7f655dd86c730bdeaea884e73741097142c92f0e3fc1811b699ba593c7fbd81da288a29d423df831652e3a01a9374999",
+            ),
+        ]
+        .iter()
+        .for_each(|test| {
+            test.run(b"QUUX-V01-CS02-with-expander-", b"SHAKE128");
+        });
+
+        [
+            // out len 0x20
+            Test::<ExpandMsgXof<Shake128>>::new(
+                b"",
+                "827c6216330a122352312bccc0c8d6e7a146c5257a776dbd9ad9d75cd880fc53",
+            ),
+            Test::<ExpandMsgXof<Shake128>>::new(
+                b"abc",
+                "690c8d82c7213b4282c6cb41c00e31ea1d3e2005f93ad19bbf6da40f15790c5c",
+            ),
+            Test::<ExpandMsgXof<Shake128>>::new(
+                b"abcdef0123456789",
+                "979e3a15064afbbcf99f62cc09fa9c85028afcf3f825eb0711894dcfc2f57057",
+            ),
+            Test::<ExpandMsgXof<Shake128>>::new(
+                b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+                "c5a9220962d9edc212c063f4f65b609755a1ed96e62f9db5d1fd6adb5a8dc52b",
+            ),
+            Test::<ExpandMsgXof<Shake128>>::new(
+                b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                "f7b96a5901af5d78ce1d071d9c383cac66a1dfadb508300ec6aeaea0d62d5d62",
+            ),
+            // out len 0x80
+            Test::<ExpandMsgXof<Shake128>>::new(
+                b"",
+                "3890dbab00a2830be398524b71c2713bbef5f4884ac2e6f070b092effdb19208c7df943dc5dcbaee3094a78c267ef276632ee2c8ea0c05363c94b6348500fae4208345dd3475fe0c834c2beac7fa7bc181692fb728c0a53d809fc8111495222ce0f38468b11becb15b32060218e285c57a60162c2c8bb5b6bded13973cd41819",
+            ),
+            Test::<ExpandMsgXof<Shake128>>::new(
+                b"abc",
+                "41b7ffa7a301b5c1441495ebb9774e2a53dbbf4e54b9a1af6a20fd41eafd69ef7b9418599c5545b1ee422f363642b01d4a53449313f68da3e49dddb9cd25b97465170537d45dcbdf92391b5bdff344db4bd06311a05bca7dcd360b6caec849c299133e5c9194f4e15e3e23cfaab4003fab776f6ac0bfae9144c6e2e1c62e7d57",
+            ),
+            Test::<ExpandMsgXof<Shake128>>::new(
+                b"abcdef0123456789",
+                "55317e4a21318472cd2290c3082957e1242241d9e0d04f47026f03401643131401071f01aa03038b2783e795bdfa8a3541c194ad5de7cb9c225133e24af6c86e748deb52e560569bd54ef4dac03465111a3a44b0ea490fb36777ff8ea9f1a8a3e8e0de3cf0880b4b2f8dd37d3a85a8b82375aee4fa0e909f9763319b55778e71",
+            ),
+            Test::<ExpandMsgXof<Shake128>>::new(
+                b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+                "19fdd2639f082e31c77717ac9bb032a22ff0958382b2dbb39020cdc78f0da43305414806abf9a561cb2d0067eb2f7bc544482f75623438ed4b4e39dd9e6e2909dd858bd8f1d57cd0fce2d3150d90aa67b4498bdf2df98c0100dd1a173436ba5d0df6be1defb0b2ce55ccd2f4fc05eb7cb2c019c35d5398b85adc676da4238bc7",
+            ),
+            Test::<ExpandMsgXof<Shake128>>::new(
+                b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                "945373f0b3431a103333ba6a0a34f1efab2702efde41754c4cb1d5216d5b0a92a67458d968562bde7fa6310a83f53dda1383680a276a283438d58ceebfa7ab7ba72499d4a3eddc860595f63c93b1c5e823ea41fc490d938398a26db28f61857698553e93f0574eb8c5017bfed6249491f9976aaa8d23d9485339cc85ca329308",
+            ),
+        ]
+        .iter()
+        .for_each(|test| {
+            let domain = [&b"SHAKE128-long-DST-"[..], &[b'1'; 210]].concat();
+            test.run(b"QUUX-V01-CS02-with-expander-", &domain);
+        });
+
+        [
+            // out len 0x20
+            Test::<ExpandMsgXof<Shake256>>::new(
+                b"",
+                "2ffc05c48ed32b95d72e807f6eab9f7530dd1c2f013914c8fed38c5ccc15ad76",
+            ),
+            Test::<ExpandMsgXof<Shake256>>::new(
+                b"abc",
+                "b39e493867e2767216792abce1f2676c197c0692aed061560ead251821808e07",
+            ),
+            Test::<ExpandMsgXof<Shake256>>::new(
+                b"abcdef0123456789",
+                "245389cf44a13f0e70af8665fe5337ec2dcd138890bb7901c4ad9cfceb054b65",
+            ),
+            Test::<ExpandMsgXof<Shake256>>::new(
+                b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+                "719b3911821e6428a5ed9b8e600f2866bcf23c8f0515e52d6c6c019a03f16f0e",
+            ),
+            Test::<ExpandMsgXof<Shake256>>::new(
+                b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                "9181ead5220b1963f1b5951f35547a5ea86a820562287d6ca4723633d17ccbbc",
+            ),
+            // out len 0x80
+            Test::<ExpandMsgXof<Shake256>>::new(
+                b"",
+                "7a1361d2d7d82d79e035b8880c5a3c86c5afa719478c007d96e6c88737a3f631dd74a2c88df79a4cb5e5d9f7504957c70d669ec6bfedc31e01e2bacc4ff3fdf9b6a00b17cc18d9d72ace7d6b81c2e481b4f73f34f9a7505dccbe8f5485f3d20c5409b0310093d5d6492dea4e18aa6979c23c8ea5de01582e9689612afbb353df",
+            ),
+            Test::<ExpandMsgXof<Shake256>>::new(
+                b"abc",
+                "a54303e6b172909783353ab05ef08dd435a558c3197db0c132134649708e0b9b4e34fb99b92a9e9e28fc1f1d8860d85897a8e021e6382f3eea10577f968ff6df6c45fe624ce65ca25932f679a42a404bc3681efe03fcd45ef73bb3a8f79ba784f80f55ea8a3c367408f30381299617f50c8cf8fbb21d0f1e1d70b0131a7b6fbe",
+            ),
+            Test::<ExpandMsgXof<Shake256>>::new(
+                b"abcdef0123456789",
+                "e42e4d9538a189316e3154b821c1bafb390f78b2f010ea404e6ac063deb8c0852fcd412e098e231e43427bd2be1330bb47b4039ad57b30ae1fc94e34993b162ff4d695e42d59d9777ea18d3848d9d336c25d2acb93adcad009bcfb9cde12286df267ada283063de0bb1505565b2eb6c90e31c48798ecdc71a71756a9110ff373",
+            ),
+            Test::<ExpandMsgXof<Shake256>>::new(
+                b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+                "4ac054dda0a38a65d0ecf7afd3c2812300027c8789655e47aecf1ecc1a2426b17444c7482c99e5907afd9c25b991990490bb9c686f43e79b4471a23a703d4b02f23c669737a886a7ec28bddb92c3a98de63ebf878aa363a501a60055c048bea11840c4717beae7eee28c3cfa42857b3d130188571943a7bd747de831bd6444e0",
+            ),
+            Test::<ExpandMsgXof<Shake256>>::new(
+                b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                "09afc76d51c2cccbc129c2315df66c2be7295a231203b8ab2dd7f95c2772c68e500bc72e20c602abc9964663b7a03a389be128c56971ce81001a0b875e7fd17822db9d69792ddf6a23a151bf470079c518279aef3e75611f8f828994a9988f4a8a256ddb8bae161e658d5a2a09bcfe839c6396dc06ee5c8ff3c22d3b1f9deb7e",
+            ),
+        ]
+        .iter()
+        .for_each(|test| {
+            test.run(b"QUUX-V01-CS02-with-expander-", b"SHAKE256");
+        });
+    }
+
+    #[test]
+    fn test_hash_to_field() {
+        use crate::tests::hex_to_field;
+        use crate::{bls12381, secp256k1, secp256r1, small_fields};
+
+        // Test vectors are the `u` of the message "" in the RFC 9380 suites.
+        // See: <https://www.rfc-editor.org/rfc/rfc9380.html#appendix-J>
+        let u = hash_to_field::<secp256r1::Fp, Sha256, 48>(
+            b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_RO_",
+            b"",
+            2,
+        );
+        assert_eq!(
+            u,
+            [
+                hex_to_field("ad5342c66a6dd0ff080df1da0ea1c04b96e0330dd89406465eeba11582515009"),
+                hex_to_field("8c0f1d43204bd6f6ea70ae8013070a1518b43873bcd850aafa0a9e220e2eea5a"),
+            ]
+        );
+
+        let u = hash_to_field::<secp256k1::Fp, Sha256, 48>(
+            b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_",
+            b"",
+            2,
+        );
+        assert_eq!(
+            u,
+            [
+                hex_to_field("6b0f9910dd2ba71c78f2ee9f04d73b5f4c5f7fc773a701abea1e573cab002fb3"),
+                hex_to_field("1ae6c212e08fe1a5937f6202f929a2cc8ef4ee5b9782db68b0d5799fd8f09e16"),
+            ]
+        );
+
+        let u = hash_to_field::<bls12381::Fq, Sha256, 64>(
+            b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_",
+            b"",
+            2,
+        );
+        assert_eq!(
+            u,
+            [
+                hex_to_field("0ba14bd907ad64a016293ee7c2d276b8eae71f25a4b941eece7b0d89f17f75cb3ae5438a614fb61d6835ad59f29c564f"),
+                hex_to_field("019b9bd7979f12657976de2884c7cce192b82c177c80e0ec604436a7f538d231552f0d96d9f7babe5fa3b19b3ff25ac9"),
+            ]
+        );
+
+        let u = hash_to_field::<bls12381::Fq2, Sha256, 128>(
+            b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_",
+            b"",
+            2,
+        );
+        assert_eq!(
+            u,
+            [
+                bls12381::Fq2 {
+                    c0: hex_to_field("03dbc2cce174e91ba93cbb08f26b917f98194a2ea08d1cce75b2b9cc9f21689d80bd79b594a613d0a68eb807dfdc1cf8"),
+                    c1: hex_to_field("05a2acec64114845711a54199ea339abd125ba38253b70a92c876df10598bd1986b739cad67961eb94f7076511b3b39a"),
+                },
+                bls12381::Fq2 {
+                    c0: hex_to_field("02f99798e8a5acdeed60d7e18e9120521ba1f47ec090984662846bc825de191b5b7641148c0dbc237726a334473eee94"),
+                    c1: hex_to_field("145a81e418d4010cc027a68f14391b30074e89e60ee7a22f87217b2f6eb0c4b94c9115b436e6fa4607e95a98de30a435"),
+                },
+            ]
+        );
+
+        // There are no published vectors for the small fields, these are the
+        // outputs of `expand_message_xmd` reduced modulo p.
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let u = hash_to_field::<small_fields::Goldilocks, Sha256, 24>(dst, b"abc", 2);
+        assert_eq!(
+            u,
+            [
+                small_fields::Goldilocks::from(0x3d82_2fdb_4f76_5655),
+                small_fields::Goldilocks::from(0xbf3b_7309_530e_8bb1),
+            ]
+        );
+        let u = hash_to_field::<small_fields::BabyBear, Sha256, 20>(dst, b"abc", 2);
+        assert_eq!(
+            u,
+            [
+                small_fields::BabyBear::from(0x0501_cba1),
+                small_fields::BabyBear::from(0x10d8_71e6),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_suite_validation() {
+        use crate::{bn256, pluto_eris, secp256k1, secp256r1};
+
+        let p256_iso = || Iso {
+            a: secp256r1::Secp256r1::a(),
+            b: secp256r1::Secp256r1::b(),
+            map: Box::new(|x, y, z| secp256r1::Secp256r1::new_jacobian(x, y, z).unwrap()),
+        };
+        let p256_suite = |z: secp256r1::Fp| {
+            Suite::<secp256r1::Secp256r1, Sha256, 48>::new(b"", z, Method::SSWU(p256_iso())).err()
+        };
+        assert_eq!(p256_suite(-secp256r1::Fp::from(10)), None);
+        assert_eq!(p256_suite(secp256r1::Fp::ONE), Some(SuiteError::SquareZ));
+        assert_eq!(p256_suite(-secp256r1::Fp::ONE), Some(SuiteError::InvalidZ));
+
+        // Z = -10 meets every other criterion on y^2 = x^3 + 4 x + 1040
+        let zero_gz_iso = Iso {
+            a: secp256r1::Fp::from(4),
+            b: secp256r1::Fp::from(1040),
+            map: Box::new(|x, y, z| secp256r1::Secp256r1::new_jacobian(x, y, z).unwrap()),
+        };
+        let suite = Suite::<secp256r1::Secp256r1, Sha256, 48>::new(
+            b"",
+            -secp256r1::Fp::from(10),
+            Method::SSWU(zero_gz_iso),
+        );
+        assert_eq!(suite.err(), Some(SuiteError::ZeroGZ));
+
+        // Z = g(1) meets every other criterion on P-256, but 1 is a root of
+        // g(x) - Z
+        let (a, b) = (secp256r1::Secp256r1::a(), secp256r1::Secp256r1::b());
+        let z = secp256r1::Fp::ONE + a + b;
+        assert_eq!(p256_suite(z), Some(SuiteError::ReducibleGZ));
+
+        let bn_suite =
+            |z: bn256::Fq| Suite::<bn256::G1, Sha256, 48>::new(b"", z, Method::SVDW).err();
+        assert_eq!(bn_suite(bn256::Fq::ONE), None);
+        assert_eq!(bn_suite(bn256::Fq::ZERO), Some(SuiteError::InvalidZ));
+
+        // 446-bit field elements need 72 bytes
+        let pluto =
+            Suite::<pluto_eris::G1, Sha256, 64>::new(b"", pluto_eris::Fp::ONE, Method::SVDW);
+        assert_eq!(pluto.err(), Some(SuiteError::InsecureLength));
+
+        // a Keccak-based suite, with the map of secp256k1
+        let suite = Suite::<secp256k1::Secp256k1, sha3::Keccak256, 48>::secp256k1_sswu(
+            b"QUUX-V01-CS02-with-secp256k1_XMD:KECCAK-256_SSWU_RO_",
+        );
+        let point = suite.hash_to_curve("", b"abc");
+        assert!(bool::from(point.is_on_curve()));
+        assert_ne!(point, secp256k1::Secp256k1::hash_to_curve("")(b"abc"));
     }
 }
@@ -3,7 +3,9 @@ pub mod ff_ext;
 pub mod fft;
 pub mod hash_to_curve;
 pub mod msm;
+pub mod poly;
 pub mod serde;
+pub mod small_fields;
 
 pub mod bls12381;
 pub mod bn256;
@@ -18,6 +20,7 @@ pub mod secq256k1;
 mod derive;
 
 // Re-export to simplify downstream dependencies.
+pub use arithmetic::CurveEndo;
 pub use ff;
 pub use group;
 pub use pairing;
@@ -0,0 +1,114 @@
+use std::fmt;
+
+use ff::Field;
+use rand_core::RngCore;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use super::{msm_best, msm_parallel, sum_bases};
+use crate::CurveAffine;
+
+/// Error returned by [`msm_checked`] and [`verify_msm`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MsmError {
+    /// The result of the MSM failed the randomized consistency check.
+    Mismatch,
+}
+
+impl fmt::Display for MsmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MsmError::Mismatch => write!(f, "msm result failed the consistency check"),
+        }
+    }
+}
+
+impl std::error::Error for MsmError {}
+
+/// Checks that `result` is the multi-scalar multiplication of `coeffs` and
+/// `bases`, for example to detect a result corrupted by faulty hardware.
+///
+/// For a random $r$, the MSM of the shifted coefficients $a_i + r$ is
+/// recomputed with [`msm_parallel`] and compared with
+/// $\mathit{result} + r \cdot S$, where $S = \sum_i B_i$ is the basis folded
+/// into a single point. The shift randomizes every digit of the second
+/// computation, so a fault is only missed if the faulty computation is off by
+/// exactly the same point, which happens with negligible probability.
+///
+/// The check costs one extra MSM of the same length: [`msm_parallel`] uses
+/// projective buckets rather than the batch-affine ones of [`msm_best`], so
+/// the two results do not share the code path being checked.
+///
+/// This function will panic if coeffs and bases have a different length.
+pub fn verify_msm<C: CurveAffine>(
+    coeffs: &[C::Scalar],
+    bases: &[C],
+    result: &C::Curve,
+    mut rng: impl RngCore,
+) -> Result<(), MsmError> {
+    assert_eq!(coeffs.len(), bases.len());
+
+    let r = C::Scalar::random(&mut rng);
+
+    // basis folded into a single point
+    let folded = sum_bases(bases);
+
+    let shifted: Vec<_> = coeffs.par_iter().map(|a| *a + r).collect();
+    let lhs = msm_parallel(&shifted, bases);
+    let rhs = *result + folded * r;
+
+    if lhs == rhs {
+        Ok(())
+    } else {
+        Err(MsmError::Mismatch)
+    }
+}
+
+/// Performs a multi-scalar multiplication operation with [`msm_best`] and
+/// checks the result with [`verify_msm`], returning an error on mismatch.
+///
+/// This costs one extra MSM, about twice as much as [`msm_best`] alone.
+///
+/// This function will panic if coeffs and bases have a different length.
+///
+/// This will use multithreading if beneficial.
+pub fn msm_checked<C: CurveAffine>(
+    coeffs: &[C::Scalar],
+    bases: &[C],
+    rng: impl RngCore,
+) -> Result<C::Curve, MsmError> {
+    let result = msm_best(coeffs, bases);
+    verify_msm(coeffs, bases, &result, rng)?;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod test {
+    use ff::Field;
+    use group::{Curve, Group};
+    use rand_core::OsRng;
+
+    use super::{msm_checked, verify_msm, MsmError};
+    use crate::bn256::{Fr, G1Affine, G1};
+    use crate::msm::msm_best;
+
+    #[test]
+    fn test_msm_checked() {
+        for n in [0, 1, 100] {
+            let points = (0..n).map(|_| G1::random(OsRng)).collect::<Vec<_>>();
+            let mut bases = vec![G1Affine::default(); n];
+            G1::batch_normalize(&points[..], &mut bases[..]);
+            let scalars = (0..n).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+
+            let result = msm_checked(&scalars, &bases, OsRng).unwrap();
+            assert_eq!(result, msm_best(&scalars, &bases));
+
+            // corrupted results
+            for fault in [G1::generator(), G1::random(OsRng)] {
+                assert_eq!(
+                    verify_msm(&scalars, &bases, &(result + fault), OsRng),
+                    Err(MsmError::Mismatch)
+                );
+            }
+        }
+    }
+}
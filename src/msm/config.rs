@@ -0,0 +1,303 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use ff::{Field, PrimeField};
+use group::{Curve, Group};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use rayon::ThreadPool;
+
+use super::{default_window, msm_bytes, msm_window, Affine, BATCH_SIZE, MAX_WINDOW};
+use crate::CurveAffine;
+
+/// Execution mode of [`msm_with_config`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MsmMode {
+    /// Runs every window on the calling thread.
+    Serial,
+    /// Runs the windows in parallel.
+    #[default]
+    Parallel,
+}
+
+/// Tuning parameters of [`msm_with_config`].
+///
+/// The window size is either fixed with [`MsmConfig::with_window`], looked up
+/// in the table measured by [`MsmConfig::calibrate`], or chosen with the same
+/// heuristic as [`super::msm_best`].
+#[derive(Clone, Debug)]
+pub struct MsmConfig {
+    window: Option<usize>,
+    // `(log2(n), window)` pairs measured by `calibrate`, sorted by size
+    calibrated: Vec<(u32, usize)>,
+    batch_size: usize,
+    thread_pool: Option<Arc<ThreadPool>>,
+    mode: MsmMode,
+}
+
+impl Default for MsmConfig {
+    fn default() -> Self {
+        Self {
+            window: None,
+            calibrated: vec![],
+            batch_size: BATCH_SIZE,
+            thread_pool: None,
+            mode: MsmMode::default(),
+        }
+    }
+}
+
+/// Candidate windows tried by [`MsmConfig::calibrate`] around the default one.
+const CALIBRATION_SPREAD: usize = 3;
+
+fn log2_floor(n: usize) -> u32 {
+    usize::BITS - 1 - n.max(1).leading_zeros()
+}
+
+impl MsmConfig {
+    /// Returns the default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Uses windows of `window` bits for every input size.
+    ///
+    /// This function will panic if `window` is zero or larger than 24.
+    pub fn with_window(mut self, window: usize) -> Self {
+        assert!(
+            window > 0 && window <= MAX_WINDOW,
+            "window size out of range"
+        );
+        self.window = Some(window);
+        self
+    }
+
+    /// Sets the number of affine additions sharing one field inversion.
+    ///
+    /// This function will panic if `batch_size` is zero.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        assert!(batch_size > 0, "batch size must be positive");
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Runs the MSM inside `thread_pool` instead of the global rayon pool.
+    pub fn with_thread_pool(mut self, thread_pool: Arc<ThreadPool>) -> Self {
+        self.thread_pool = Some(thread_pool);
+        self
+    }
+
+    /// Sets the execution mode.
+    pub fn with_mode(mut self, mode: MsmMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Returns the window size used for an MSM of `len` terms.
+    pub fn window(&self, len: usize) -> usize {
+        if let Some(window) = self.window {
+            return window;
+        }
+        let log_n = log2_floor(len);
+        self.calibrated
+            .iter()
+            .min_by_key(|(k, _)| k.abs_diff(log_n))
+            .map(|(_, window)| *window)
+            .unwrap_or_else(|| default_window(len))
+    }
+
+    /// Returns the number of affine additions sharing one field inversion.
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// Returns the execution mode.
+    pub fn mode(&self) -> MsmMode {
+        self.mode
+    }
+
+    /// Measures, for every `k` in `log_sizes`, the window size giving the
+    /// fastest MSM of $2^k$ terms over `C` on the current machine, using the
+    /// batch size, thread pool and mode of this configuration.
+    ///
+    /// The measured windows are then used for inputs of the nearest size,
+    /// unless a window was fixed with [`MsmConfig::with_window`].
+    pub fn calibrate<C: CurveAffine>(mut self, log_sizes: &[u32]) -> Self {
+        let max_k = match log_sizes.iter().max() {
+            Some(max_k) => *max_k,
+            None => return self,
+        };
+
+        let mut rng = StdRng::seed_from_u64(0);
+        // The running time does not depend on the bases, consecutive multiples
+        // of a random point are much cheaper to generate than random points.
+        let g = C::Curve::random(&mut rng);
+        let points: Vec<_> = std::iter::successors(Some(g), |p| Some(*p + g))
+            .take(1 << max_k)
+            .collect();
+        let mut bases = vec![C::identity(); points.len()];
+        C::Curve::batch_normalize(&points, &mut bases);
+        let coeffs: Vec<_> = (0..bases.len())
+            .map(|_| C::Scalar::random(&mut rng))
+            .collect();
+
+        for &k in log_sizes {
+            let n = 1 << k;
+            let default = default_window(n);
+            let candidates = default.saturating_sub(CALIBRATION_SPREAD).max(1)
+                ..=(default + CALIBRATION_SPREAD).min(MAX_WINDOW);
+
+            let best = candidates
+                .map(|window| {
+                    let mut probe = self.clone();
+                    probe.window = Some(window);
+                    let elapsed = (0..2)
+                        .map(|_| {
+                            let start = Instant::now();
+                            msm_with_config(&coeffs[..n], &bases[..n], &probe);
+                            start.elapsed()
+                        })
+                        .min()
+                        .unwrap_or(Duration::MAX);
+                    (elapsed, window)
+                })
+                .min()
+                .map(|(_, window)| window)
+                .unwrap_or(default);
+
+            self.calibrated.retain(|(size, _)| *size != k);
+            self.calibrated.push((k, best));
+        }
+        self.calibrated.sort_unstable();
+        self
+    }
+}
+
+/// Performs a multi-scalar multiplication operation with the window size,
+/// batch size, thread pool and execution mode given by `config`.
+///
+/// This function will panic if coeffs and bases have a different length.
+pub fn msm_with_config<C: CurveAffine>(
+    coeffs: &[C::Scalar],
+    bases: &[C],
+    config: &MsmConfig,
+) -> C::Curve {
+    assert_eq!(coeffs.len(), bases.len());
+
+    match &config.thread_pool {
+        Some(thread_pool) => thread_pool.install(|| msm_configured(coeffs, bases, config)),
+        None => msm_configured(coeffs, bases, config),
+    }
+}
+
+fn msm_configured<C: CurveAffine>(
+    coeffs: &[C::Scalar],
+    bases: &[C],
+    config: &MsmConfig,
+) -> C::Curve {
+    let c = config.window(bases.len());
+    let num_bits = C::Scalar::NUM_BITS as usize;
+
+    match config.mode {
+        MsmMode::Serial => {
+            // coeffs to byte representation
+            let coeffs: Vec<_> = coeffs.iter().map(|a| a.to_repr()).collect();
+            // copy bases into `Affine` to skip in on curve check for every access
+            let bases_local: Vec<_> = bases.iter().map(Affine::from).collect();
+
+            let mut acc = C::Curve::identity();
+            for w in (0..num_bits / c + 1).rev() {
+                for _ in 0..c {
+                    acc = acc.double();
+                }
+                acc += msm_window(&coeffs, bases, &bases_local, c, w, config.batch_size);
+            }
+            acc
+        }
+        MsmMode::Parallel => {
+            // coeffs to byte representation
+            let coeffs: Vec<_> = coeffs.par_iter().map(|a| a.to_repr()).collect();
+            // copy bases into `Affine` to skip in on curve check for every access
+            let bases_local: Vec<_> = bases.par_iter().map(Affine::from).collect();
+
+            msm_bytes(&coeffs, bases, &bases_local, num_bits, c, config.batch_size)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use ff::Field;
+    use group::{Curve, Group};
+    use rand_core::OsRng;
+
+    use super::{msm_with_config, MsmConfig, MsmMode};
+    use crate::bn256::{Fr, G1Affine, G1};
+    use crate::msm::{msm_best, MAX_WINDOW};
+
+    #[test]
+    fn test_msm_with_config() {
+        let k = 10;
+        let points = (0..1 << k).map(|_| G1::random(OsRng)).collect::<Vec<_>>();
+        let mut bases = vec![G1Affine::default(); 1 << k];
+        G1::batch_normalize(&points[..], &mut bases[..]);
+        let scalars = (0..1 << k).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+
+        let pool = Arc::new(
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(2)
+                .build()
+                .unwrap(),
+        );
+
+        for n in [0, 1, 5, 1 << k] {
+            let expected = msm_best(&scalars[..n], &bases[..n]);
+            for mode in [MsmMode::Serial, MsmMode::Parallel] {
+                let configs = [
+                    MsmConfig::new(),
+                    MsmConfig::new().with_window(1),
+                    MsmConfig::new().with_window(11).with_batch_size(1),
+                    MsmConfig::new().with_window(4).with_batch_size(7),
+                    MsmConfig::new().with_thread_pool(pool.clone()),
+                ];
+                for config in configs {
+                    let config = config.with_mode(mode);
+                    assert_eq!(
+                        msm_with_config(&scalars[..n], &bases[..n], &config),
+                        expected
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_msm_calibrate() {
+        let config = MsmConfig::new()
+            .with_mode(MsmMode::Serial)
+            .calibrate::<G1Affine>(&[3, 6]);
+        for n in [1 << 3, 1 << 6, 1 << 10] {
+            let window = config.window(n);
+            assert!((1..=MAX_WINDOW).contains(&window));
+        }
+        assert_eq!(config.clone().with_window(5).window(1 << 6), 5);
+
+        let bases = (0..1 << 6)
+            .map(|_| G1Affine::random(OsRng))
+            .collect::<Vec<_>>();
+        let scalars = (0..1 << 6).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+        assert_eq!(
+            msm_with_config(&scalars, &bases, &config),
+            msm_best(&scalars, &bases)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "window size out of range")]
+    fn test_msm_window_too_large() {
+        MsmConfig::new().with_window(MAX_WINDOW + 1);
+    }
+}
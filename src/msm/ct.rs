@@ -0,0 +1,150 @@
+use ff::PrimeField;
+use group::Group;
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+use rayon::slice::ParallelSlice;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use super::get_booth_window;
+use crate::CurveAffine;
+
+/// Window size of [`msm_ct`]. Every base gets a table of
+/// `2^(CT_WINDOW - 1) + 1` multiples.
+const CT_WINDOW: usize = 4;
+
+/// Constant-time counterpart of [`super::get_booth_index`]: returns the
+/// absolute value of the Booth digit and whether it is negative, without
+/// branching on the bits of `el`.
+fn get_booth_index_ct(window_index: usize, window_size: usize, el: &[u8]) -> (u32, Choice) {
+    let tmp = get_booth_window(window_index, window_size, el);
+
+    let neg = Choice::from(((tmp >> window_size) & 1) as u8);
+
+    // div ceil by 2
+    let pos_idx = (tmp + 1) >> 1;
+    let neg_idx = !pos_idx.wrapping_sub(1) & ((1 << window_size) - 1);
+
+    (u32::conditional_select(&pos_idx, &neg_idx, neg), neg)
+}
+
+/// Performs a multi-scalar multiplication operation in time independent of
+/// the values of the scalars, for use with secret scalars such as the
+/// witnesses of blinded Pedersen commitments.
+///
+/// Every scalar is recoded in signed Booth digits with a fixed window size and
+/// every window of every scalar is processed, zero digits included. The
+/// multiple of each base is read from a per-base table with
+/// `conditional_select` over all of its entries, conditionally negated, and
+/// added with the complete addition formulas of the curve. The memory access
+/// pattern and the sequence of group operations therefore only depend on the
+/// number of bases.
+///
+/// This assumes constant-time field arithmetic, which holds for the fields of
+/// this crate. The bases are not protected and may leak through timing.
+///
+/// This is considerably slower than [`super::msm_best`] and should only be
+/// used when the scalars must be kept secret.
+///
+/// This function will panic if coeffs and bases have a different length.
+///
+/// This will use multithreading if beneficial.
+pub fn msm_ct<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve {
+    assert_eq!(coeffs.len(), bases.len());
+
+    let c = CT_WINDOW;
+    let number_of_windows = C::Scalar::NUM_BITS as usize / c + 1;
+    let table_size = (1 << (c - 1)) + 1;
+
+    let num_threads = rayon::current_num_threads();
+    let chunk = ((coeffs.len() + num_threads - 1) / num_threads).max(1);
+
+    coeffs
+        .par_chunks(chunk)
+        .zip(bases.par_chunks(chunk))
+        .map(|(coeffs, bases)| {
+            // coeffs to byte representation
+            let coeffs: Vec<_> = coeffs.iter().map(|a| a.to_repr()).collect();
+
+            // `tables[i * table_size + j] = j * bases[i]`
+            let mut tables = Vec::with_capacity(bases.len() * table_size);
+            for base in bases.iter() {
+                let mut acc = C::Curve::identity();
+                for _ in 0..table_size {
+                    tables.push(acc);
+                    acc += base;
+                }
+            }
+
+            let mut acc = C::Curve::identity();
+            for w in (0..number_of_windows).rev() {
+                for _ in 0..c {
+                    acc = acc.double();
+                }
+
+                for (coeff, table) in coeffs.iter().zip(tables.chunks(table_size)) {
+                    let (idx, neg) = get_booth_index_ct(w, c, coeff.as_ref());
+
+                    // scan the whole table
+                    let mut point = C::Curve::identity();
+                    for (j, entry) in table.iter().enumerate() {
+                        point.conditional_assign(entry, (j as u32).ct_eq(&idx));
+                    }
+                    let point = C::Curve::conditional_select(&point, &-point, neg);
+
+                    acc += point;
+                }
+            }
+            acc
+        })
+        .reduce(C::Curve::identity, |a, b| a + b)
+}
+
+#[cfg(test)]
+mod test {
+    use ff::{Field, PrimeField};
+    use group::{Curve, Group};
+    use rand_core::OsRng;
+
+    use super::{get_booth_index_ct, msm_ct};
+    use crate::msm::{get_booth_index, msm_best};
+    use crate::CurveAffine;
+
+    #[test]
+    fn test_booth_encoding_ct() {
+        for _ in 0..10 {
+            let scalar = crate::bn256::Fr::random(OsRng).to_repr();
+            for window in 1..10 {
+                for w in 0..crate::bn256::Fr::NUM_BITS as usize / window + 1 {
+                    let expected = get_booth_index(w, window, scalar.as_ref());
+                    let (idx, neg) = get_booth_index_ct(w, window, scalar.as_ref());
+                    assert_eq!(idx, expected.unsigned_abs());
+                    if expected != 0 {
+                        assert_eq!(bool::from(neg), expected.is_negative());
+                    }
+                }
+            }
+        }
+    }
+
+    fn run_msm_ct<C: CurveAffine>(n: usize) {
+        let points = (0..n).map(|_| C::Curve::random(OsRng)).collect::<Vec<_>>();
+        let mut bases = vec![C::identity(); n];
+        C::Curve::batch_normalize(&points[..], &mut bases[..]);
+        let mut scalars = (0..n).map(|_| C::Scalar::random(OsRng)).collect::<Vec<_>>();
+        if n > 2 {
+            bases[0] = C::identity();
+            scalars[1] = C::Scalar::ZERO;
+            scalars[2] = -C::Scalar::ONE;
+        }
+        assert_eq!(msm_ct(&scalars, &bases), msm_best(&scalars, &bases));
+    }
+
+    #[test]
+    fn test_msm_ct() {
+        for n in [0, 1, 3, 100] {
+            run_msm_ct::<crate::bn256::G1Affine>(n);
+        }
+        run_msm_ct::<crate::secp256r1::Secp256r1Affine>(50);
+        run_msm_ct::<crate::bls12381::G2Affine>(10);
+        run_msm_ct::<crate::pluto_eris::G1Affine>(10);
+    }
+}
@@ -0,0 +1,340 @@
+use std::io::{self, Read, Write};
+
+use ff::PrimeField;
+use group::{Curve, Group};
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use rayon::slice::{ParallelSlice, ParallelSliceMut};
+
+use super::{default_window, get_booth_index, Affine, Bucket, Schedule, BATCH_SIZE, MAX_WINDOW};
+use crate::serde::SerdeObject;
+use crate::CurveAffine;
+
+/// Size in bytes of the header written in front of the serialized table:
+/// the window size as a `u32` followed by the number of bases as a `u64`.
+const HEADER_SIZE: usize = 4 + 8;
+
+/// Multi-scalar multiplication context for a fixed set of bases.
+///
+/// For every base $B_i$ and every Booth window $w$ the point $2^{c \cdot w} B_i$
+/// is precomputed once, where $c$ is the window size. All windows of all
+/// scalars can then be accumulated into a single set of buckets, so each
+/// subsequent MSM is made only of table lookups and additions: no doublings
+/// are performed.
+///
+/// The table holds `bases.len() * (NUM_BITS / c + 1)` points. It can be stored
+/// and reloaded through [`SerdeObject`] whenever the bases implement it.
+#[derive(Debug, Clone)]
+pub struct FixedBaseMsm<C: CurveAffine> {
+    window: usize,
+    num_windows: usize,
+    len: usize,
+    // `table[i * num_windows + w] = 2^(window * w) * bases[i]`, with the
+    // coordinates extracted to skip the on curve check for every access
+    table: Vec<Affine<C>>,
+}
+
+impl<C: CurveAffine> FixedBaseMsm<C> {
+    /// Precomputes the table for `bases`, choosing the window size with the
+    /// same heuristic as [`super::msm_best`].
+    pub fn new(bases: &[C]) -> Self {
+        Self::with_window(bases, default_window(bases.len()))
+    }
+
+    /// Precomputes the table for `bases` using windows of `window` bits.
+    ///
+    /// Larger windows shrink the table and the number of additions per MSM at
+    /// the cost of more buckets.
+    ///
+    /// This function will panic if `window` is zero or larger than 24.
+    pub fn with_window(bases: &[C], window: usize) -> Self {
+        assert!(
+            window > 0 && window <= MAX_WINDOW,
+            "window size out of range"
+        );
+        let num_windows = C::Scalar::NUM_BITS as usize / window + 1;
+
+        let mut table_proj = vec![C::Curve::identity(); bases.len() * num_windows];
+        table_proj
+            .par_chunks_mut(num_windows)
+            .zip(bases.par_iter())
+            .for_each(|(row, base)| {
+                let mut acc = base.to_curve();
+                for entry in row.iter_mut() {
+                    *entry = acc;
+                    for _ in 0..window {
+                        acc = acc.double();
+                    }
+                }
+            });
+
+        let mut table = vec![C::identity(); table_proj.len()];
+        table
+            .par_chunks_mut(1 << 10)
+            .zip(table_proj.par_chunks(1 << 10))
+            .for_each(|(table, table_proj)| C::Curve::batch_normalize(table_proj, table));
+
+        Self::from_table(window, bases.len(), table)
+    }
+
+    fn from_table(window: usize, len: usize, table: Vec<C>) -> Self {
+        let num_windows = C::Scalar::NUM_BITS as usize / window + 1;
+        assert_eq!(table.len(), len * num_windows);
+
+        Self {
+            window,
+            num_windows,
+            len,
+            table: table.par_iter().map(Affine::from).collect(),
+        }
+    }
+
+    /// Returns the window size used by the table.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Returns the number of bases.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the context was built over an empty set of bases.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Computes $\sum_i \mathit{coeffs}_i \cdot \mathit{bases}_i$ over the
+    /// precomputed bases.
+    ///
+    /// This function will panic if `coeffs` does not have as many elements as
+    /// there are bases.
+    ///
+    /// This will use multithreading if beneficial.
+    pub fn msm(&self, coeffs: &[C::Scalar]) -> C::Curve {
+        assert_eq!(coeffs.len(), self.len);
+
+        let c = self.window;
+        let num_windows = self.num_windows;
+
+        // coeffs to byte representation
+        let coeffs: Vec<_> = coeffs.par_iter().map(|a| a.to_repr()).collect();
+
+        let num_threads = rayon::current_num_threads();
+        let chunk = ((coeffs.len() + num_threads - 1) / num_threads).max(1);
+
+        coeffs
+            .par_chunks(chunk)
+            .enumerate()
+            .map(|(chunk_idx, coeffs)| {
+                // jacobian buckets for already scheduled points
+                let mut j_bucks = vec![Bucket::<C>::None; 1 << (c - 1)];
+
+                // schedular for affine addition
+                let mut sched = Schedule::new(c, BATCH_SIZE);
+
+                for (i, coeff) in coeffs.iter().enumerate() {
+                    let row = (chunk_idx * chunk + i) * num_windows;
+                    // batch addition assumes bases are never the identity
+                    if self.table[row].is_identity() {
+                        continue;
+                    }
+
+                    for w in 0..num_windows {
+                        let buck_idx = get_booth_index(w, c, coeff.as_ref());
+
+                        if buck_idx != 0 {
+                            // parse bucket index
+                            let sign = buck_idx.is_positive();
+                            let buck_idx = buck_idx.unsigned_abs() as usize - 1;
+                            let base_idx = row + w;
+
+                            if sched.contains(buck_idx) {
+                                // greedy accumulation
+                                // we use original bases here
+                                j_bucks[buck_idx].add_assign(&self.table[base_idx].eval(), sign);
+                            } else {
+                                // also flushes the schedule if full
+                                sched.add(&self.table, base_idx, buck_idx, sign);
+                            }
+                        }
+                    }
+                }
+
+                // flush the schedule
+                sched.execute(&self.table);
+
+                // summation by parts
+                let mut acc = C::Curve::identity();
+                let mut running_sum = C::Curve::identity();
+                for (j_buck, a_buck) in j_bucks.iter().zip(sched.buckets.iter()).rev() {
+                    running_sum += j_buck.add(a_buck);
+                    acc += running_sum;
+                }
+                acc
+            })
+            .reduce(C::Curve::identity, |a, b| a + b)
+    }
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// The serialized form is the window size (`u32`, little endian), the number of
+/// bases (`u64`, little endian) and the raw table points, base by base.
+impl<C: CurveAffine + SerdeObject> SerdeObject for FixedBaseMsm<C> {
+    fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
+        Self::read_raw_unchecked(&mut &bytes[..])
+    }
+
+    /// Checks the header and that every table point is on the curve. The
+    /// relation between the points of a row is not checked.
+    fn from_raw_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut reader = bytes;
+        let res = Self::read_raw(&mut reader).ok()?;
+        reader.is_empty().then_some(res)
+    }
+
+    fn to_raw_bytes(&self) -> Vec<u8> {
+        let mut res = Vec::with_capacity(HEADER_SIZE);
+        self.write_raw(&mut res).unwrap();
+        res
+    }
+
+    fn read_raw_unchecked<R: Read>(reader: &mut R) -> Self {
+        let mut header = [0u8; HEADER_SIZE];
+        reader.read_exact(&mut header).unwrap();
+        let window = u32::from_le_bytes(header[0..4].try_into().unwrap()) as usize;
+        let len = u64::from_le_bytes(header[4..12].try_into().unwrap()) as usize;
+        assert!(
+            window > 0 && window <= MAX_WINDOW,
+            "window size out of range"
+        );
+        let num_windows = C::Scalar::NUM_BITS as usize / window + 1;
+
+        let table = (0..len * num_windows)
+            .map(|_| C::read_raw_unchecked(reader))
+            .collect();
+        Self::from_table(window, len, table)
+    }
+
+    fn read_raw<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut header = [0u8; HEADER_SIZE];
+        reader.read_exact(&mut header)?;
+        let window = u32::from_le_bytes(header[0..4].try_into().unwrap()) as usize;
+        let len = u64::from_le_bytes(header[4..12].try_into().unwrap());
+        if window == 0 || window > MAX_WINDOW {
+            return Err(invalid_data("window size out of range"));
+        }
+        let len = usize::try_from(len).map_err(|_| invalid_data("too many bases"))?;
+        let num_windows = C::Scalar::NUM_BITS as usize / window + 1;
+        let size = len
+            .checked_mul(num_windows)
+            .ok_or_else(|| invalid_data("too many bases"))?;
+
+        let table = (0..size)
+            .map(|_| C::read_raw(reader))
+            .collect::<io::Result<Vec<_>>>()?;
+        if !table.iter().all(|p| bool::from(p.is_on_curve())) {
+            return Err(invalid_data("point not on the curve"));
+        }
+        Ok(Self::from_table(window, len, table))
+    }
+
+    fn write_raw<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&(self.window as u32).to_le_bytes())?;
+        writer.write_all(&(self.len as u64).to_le_bytes())?;
+        for point in self.table.iter() {
+            point.point().write_raw(writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ff::Field;
+    use group::{Curve, Group};
+    use rand_core::OsRng;
+
+    use super::FixedBaseMsm;
+    use crate::msm::{msm_best, MAX_WINDOW};
+    use crate::serde::SerdeObject;
+    use crate::CurveAffine;
+
+    fn run_fixed_base_msm<C: CurveAffine>(n: usize) -> FixedBaseMsm<C> {
+        let points = (0..n).map(|_| C::Curve::random(OsRng)).collect::<Vec<_>>();
+        let mut bases = vec![C::identity(); n];
+        C::Curve::batch_normalize(&points[..], &mut bases[..]);
+        // identity bases must be skipped
+        bases[n / 2] = C::identity();
+
+        let ctx = FixedBaseMsm::new(&bases);
+        for window in [1, 4, ctx.window()] {
+            let ctx = FixedBaseMsm::with_window(&bases, window);
+            for _ in 0..2 {
+                let mut scalars = (0..n).map(|_| C::Scalar::random(OsRng)).collect::<Vec<_>>();
+                scalars[0] = C::Scalar::ZERO;
+                scalars[1] = -C::Scalar::ONE;
+                assert_eq!(ctx.msm(&scalars), msm_best(&scalars, &bases));
+            }
+        }
+        ctx
+    }
+
+    fn run_fixed_base_msm_serde<C: CurveAffine + SerdeObject>(n: usize) {
+        let ctx = run_fixed_base_msm::<C>(n);
+        let bytes = ctx.to_raw_bytes();
+
+        let scalars = (0..n).map(|_| C::Scalar::random(OsRng)).collect::<Vec<_>>();
+        let expected = ctx.msm(&scalars);
+
+        let loaded = FixedBaseMsm::<C>::from_raw_bytes(&bytes).unwrap();
+        assert_eq!(loaded.window(), ctx.window());
+        assert_eq!(loaded.msm(&scalars), expected);
+
+        let loaded = FixedBaseMsm::<C>::read_raw_unchecked(&mut &bytes[..]);
+        assert_eq!(loaded.msm(&scalars), expected);
+
+        assert!(FixedBaseMsm::<C>::from_raw_bytes(&bytes[..bytes.len() - 1]).is_none());
+
+        for window in [0, MAX_WINDOW as u32 + 1, 32] {
+            let mut bytes = bytes.clone();
+            bytes[0..4].copy_from_slice(&window.to_le_bytes());
+            assert!(FixedBaseMsm::<C>::from_raw_bytes(&bytes).is_none());
+        }
+    }
+
+    #[test]
+    fn test_fixed_base_msm() {
+        const N: usize = 40;
+        run_fixed_base_msm_serde::<crate::bn256::G1Affine>(N);
+        run_fixed_base_msm_serde::<crate::bn256::G2Affine>(N);
+        run_fixed_base_msm_serde::<crate::grumpkin::G1Affine>(N);
+        run_fixed_base_msm_serde::<crate::bls12381::G1Affine>(N);
+        run_fixed_base_msm_serde::<crate::bls12381::G2Affine>(N);
+        run_fixed_base_msm_serde::<crate::secp256k1::Secp256k1Affine>(N);
+        run_fixed_base_msm_serde::<crate::secp256r1::Secp256r1Affine>(N);
+        run_fixed_base_msm_serde::<crate::secq256k1::Secq256k1Affine>(N);
+        run_fixed_base_msm_serde::<crate::pluto_eris::G1Affine>(N);
+        run_fixed_base_msm_serde::<crate::pluto_eris::ErisAffine>(N);
+        run_fixed_base_msm_serde::<crate::pluto_eris::G2Affine>(N);
+        run_fixed_base_msm::<crate::pasta::EqAffine>(N);
+        run_fixed_base_msm::<crate::pasta::EpAffine>(N);
+    }
+
+    #[test]
+    fn test_fixed_base_msm_empty() {
+        let ctx = FixedBaseMsm::<crate::bn256::G1Affine>::new(&[]);
+        assert!(ctx.is_empty());
+        assert_eq!(ctx.msm(&[]), crate::bn256::G1::identity());
+    }
+
+    #[test]
+    #[should_panic(expected = "window size out of range")]
+    fn test_fixed_base_msm_unchecked_zero_window() {
+        let mut bytes = FixedBaseMsm::<crate::bn256::G1Affine>::new(&[]).to_raw_bytes();
+        bytes[0..4].copy_from_slice(&0u32.to_le_bytes());
+        FixedBaseMsm::<crate::bn256::G1Affine>::read_raw_unchecked(&mut &bytes[..]);
+    }
+}
@@ -0,0 +1,1251 @@
+use std::ops::Neg;
+
+use crate::arithmetic::CurveEndo;
+use crate::{CurveAffine, CurveExt};
+use ff::Field;
+use ff::PrimeField;
+use group::{Curve, Group};
+use rayon::iter::{
+    IndexedParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator,
+};
+
+mod checked;
+mod config;
+mod ct;
+mod fixed_base;
+mod stream;
+
+pub use checked::{msm_checked, verify_msm, MsmError};
+pub use config::{msm_with_config, MsmConfig, MsmMode};
+pub use ct::msm_ct;
+pub use fixed_base::FixedBaseMsm;
+pub use stream::{msm_chunked, msm_read};
+
+const BATCH_SIZE: usize = 64;
+
+/// Largest window size supported by [`get_booth_window`]: the `window + 1`
+/// bits are read from four bytes after a shift of up to seven bits.
+const MAX_WINDOW: usize = 24;
+
+/// Window size heuristic for an MSM of `len` terms.
+fn default_window(len: usize) -> usize {
+    // TODO: consider adjusting it with emprical data?
+    if len < 4 {
+        1
+    } else if len < 32 {
+        3
+    } else {
+        (f64::from(len as u32)).ln().ceil() as usize
+    }
+}
+
+/// Returns the `window_size + 1` bits of `el` read by the Booth window
+/// `window_index`, see [`get_booth_index`].
+fn get_booth_window(window_index: usize, window_size: usize, el: &[u8]) -> u32 {
+    // Booth encoding:
+    // * step by `window` size
+    // * slice by size of `window + 1``
+    // * each window overlap by 1 bit * append a zero bit to the least significant end
+    // Indexing rule for example window size 3 where we slice by 4 bits:
+    // `[0, +1, +1, +2, +2, +3, +3, +4, -4, -3, -3 -2, -2, -1, -1, 0]``
+    // So we can reduce the bucket size without preprocessing scalars
+    // and remembering them as in classic signed digit encoding
+
+    let skip_bits = (window_index * window_size).saturating_sub(1);
+    let skip_bytes = skip_bits / 8;
+
+    // fill into a u32
+    let mut v: [u8; 4] = [0; 4];
+    for (dst, src) in v.iter_mut().zip(el.iter().skip(skip_bytes)) {
+        *dst = *src
+    }
+    let mut tmp = u32::from_le_bytes(v);
+
+    // pad with one 0 if slicing the least significant window
+    if window_index == 0 {
+        tmp <<= 1;
+    }
+
+    // remove further bits
+    tmp >>= skip_bits - (skip_bytes * 8);
+    // apply the booth window
+    tmp & ((1 << (window_size + 1)) - 1)
+}
+
+fn get_booth_index(window_index: usize, window_size: usize, el: &[u8]) -> i32 {
+    let mut tmp = get_booth_window(window_index, window_size, el);
+
+    let sign = tmp & (1 << window_size) == 0;
+
+    // div ceil by 2
+    tmp = (tmp + 1) >> 1;
+
+    // find the booth action index
+    if sign {
+        tmp as i32
+    } else {
+        ((!(tmp - 1) & ((1 << window_size) - 1)) as i32).neg()
+    }
+}
+
+/// Batch addition.
+fn batch_add<C: CurveAffine>(
+    size: usize,
+    buckets: &mut [BucketAffine<C>],
+    points: &[SchedulePoint],
+    bases: &[Affine<C>],
+) {
+    let mut t = vec![C::Base::ZERO; size]; // Stores x2 - x1
+    let mut z = vec![C::Base::ZERO; size]; // Stores y2 - y1
+    let mut acc = C::Base::ONE;
+
+    for (
+        (
+            SchedulePoint {
+                base_idx,
+                buck_idx,
+                sign,
+            },
+            t,
+        ),
+        z,
+    ) in points.iter().zip(t.iter_mut()).zip(z.iter_mut())
+    {
+        if buckets[*buck_idx].is_inf() {
+            // We assume bases[*base_idx] != infinity always.
+            continue;
+        }
+
+        if buckets[*buck_idx].x() == bases[*base_idx].x {
+            // y-coordinate matches:
+            //  1. y1 == y2 and sign = false or
+            //  2. y1 != y2 and sign = true
+            //  => ( y1 == y2) xor !sign
+            //  (This uses the fact that x1 == x2 and both points satisfy the curve eq.)
+            if (buckets[*buck_idx].y() == bases[*base_idx].y) ^ !*sign {
+                // Doubling. `2y` is never zero: a point of order two has no
+                // multiple in a group of odd prime order, which all the bases
+                // and buckets of the curves in this crate belong to.
+                let x_squared = bases[*base_idx].x.square();
+                *z = buckets[*buck_idx].y() + buckets[*buck_idx].y(); // 2y
+                *t = acc * (x_squared + x_squared + x_squared + C::a()); // acc * (3x^2 + a)
+                acc *= *z;
+                continue;
+            }
+            // P + (-P)
+            buckets[*buck_idx].set_inf();
+            continue;
+        }
+        // Addition
+        *z = buckets[*buck_idx].x() - bases[*base_idx].x; // x2 - x1
+        if *sign {
+            *t = acc * (buckets[*buck_idx].y() - bases[*base_idx].y);
+        } else {
+            *t = acc * (buckets[*buck_idx].y() + bases[*base_idx].y);
+        } // y2 - y1
+        acc *= *z;
+    }
+
+    acc = acc
+        .invert()
+        .expect("Some edge case has not been handled properly");
+
+    for (
+        (
+            SchedulePoint {
+                base_idx,
+                buck_idx,
+                sign,
+            },
+            t,
+        ),
+        z,
+    ) in points.iter().zip(t.iter()).zip(z.iter()).rev()
+    {
+        if buckets[*buck_idx].is_inf() {
+            // We assume bases[*base_idx] != infinity always.
+            continue;
+        }
+        let lambda = acc * t;
+        acc *= z; // update acc
+        let x = lambda.square() - (buckets[*buck_idx].x() + bases[*base_idx].x); // x_result
+        if *sign {
+            buckets[*buck_idx].set_y(&((lambda * (bases[*base_idx].x - x)) - bases[*base_idx].y));
+        } else {
+            buckets[*buck_idx].set_y(&((lambda * (bases[*base_idx].x - x)) + bases[*base_idx].y));
+        } // y_result = lambda * (x1 - x_result) - y1
+        buckets[*buck_idx].set_x(&x);
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Affine<C: CurveAffine> {
+    x: C::Base,
+    y: C::Base,
+}
+
+impl<C: CurveAffine> Affine<C> {
+    /// The identity is mapped to `(0, 0)`, which is not on any of the curves.
+    fn from(point: &C) -> Self {
+        Option::from(point.coordinates())
+            .map(|coords: crate::Coordinates<C>| Self {
+                x: *coords.x(),
+                y: *coords.y(),
+            })
+            .unwrap_or(Self {
+                x: C::Base::ZERO,
+                y: C::Base::ZERO,
+            })
+    }
+
+    fn is_identity(&self) -> bool {
+        bool::from(self.x.is_zero() & self.y.is_zero())
+    }
+
+    fn neg(&self) -> Self {
+        Self {
+            x: self.x,
+            y: -self.y,
+        }
+    }
+
+    fn eval(&self) -> C {
+        C::from_xy(self.x, self.y).unwrap()
+    }
+
+    /// Inverse of [`Self::from`], including for the identity.
+    fn point(&self) -> C {
+        if self.is_identity() {
+            C::identity()
+        } else {
+            self.eval()
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum BucketAffine<C: CurveAffine> {
+    None,
+    Point(Affine<C>),
+}
+
+#[derive(Debug, Clone)]
+enum Bucket<C: CurveAffine> {
+    None,
+    Point(C::Curve),
+}
+
+impl<C: CurveAffine> Bucket<C> {
+    fn add_assign(&mut self, point: &C, sign: bool) {
+        *self = match *self {
+            Bucket::None => Bucket::Point({
+                if sign {
+                    point.to_curve()
+                } else {
+                    point.to_curve().neg()
+                }
+            }),
+            Bucket::Point(a) => {
+                if sign {
+                    Self::Point(a + point)
+                } else {
+                    Self::Point(a - point)
+                }
+            }
+        }
+    }
+
+    fn add(&self, other: &BucketAffine<C>) -> C::Curve {
+        match (self, other) {
+            (Self::Point(this), BucketAffine::Point(other)) => *this + other.eval(),
+            (Self::Point(this), BucketAffine::None) => *this,
+            (Self::None, BucketAffine::Point(other)) => other.eval().to_curve(),
+            (Self::None, BucketAffine::None) => C::Curve::identity(),
+        }
+    }
+}
+
+impl<C: CurveAffine> BucketAffine<C> {
+    fn assign(&mut self, point: &Affine<C>, sign: bool) -> bool {
+        match *self {
+            Self::None => {
+                *self = Self::Point(if sign { *point } else { point.neg() });
+                true
+            }
+            Self::Point(_) => false,
+        }
+    }
+
+    fn x(&self) -> C::Base {
+        match self {
+            Self::None => panic!("::x None"),
+            Self::Point(a) => a.x,
+        }
+    }
+
+    fn y(&self) -> C::Base {
+        match self {
+            Self::None => panic!("::y None"),
+            Self::Point(a) => a.y,
+        }
+    }
+
+    fn is_inf(&self) -> bool {
+        match self {
+            Self::None => true,
+            Self::Point(_) => false,
+        }
+    }
+
+    fn set_x(&mut self, x: &C::Base) {
+        match self {
+            Self::None => panic!("::set_x None"),
+            Self::Point(ref mut a) => a.x = *x,
+        }
+    }
+
+    fn set_y(&mut self, y: &C::Base) {
+        match self {
+            Self::None => panic!("::set_y None"),
+            Self::Point(ref mut a) => a.y = *y,
+        }
+    }
+
+    fn set_inf(&mut self) {
+        match self {
+            Self::None => {}
+            Self::Point(_) => *self = Self::None,
+        }
+    }
+}
+
+struct Schedule<C: CurveAffine> {
+    buckets: Vec<BucketAffine<C>>,
+    set: Vec<SchedulePoint>,
+    ptr: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+struct SchedulePoint {
+    base_idx: usize,
+    buck_idx: usize,
+    sign: bool,
+}
+
+impl SchedulePoint {
+    fn new(base_idx: usize, buck_idx: usize, sign: bool) -> Self {
+        Self {
+            base_idx,
+            buck_idx,
+            sign,
+        }
+    }
+}
+
+impl<C: CurveAffine> Schedule<C> {
+    fn new(c: usize, batch_size: usize) -> Self {
+        let set = vec![SchedulePoint::default(); batch_size];
+
+        Self {
+            buckets: vec![BucketAffine::None; 1 << (c - 1)],
+            set,
+            ptr: 0,
+        }
+    }
+
+    fn contains(&self, buck_idx: usize) -> bool {
+        self.set.iter().any(|sch| sch.buck_idx == buck_idx)
+    }
+
+    fn execute(&mut self, bases: &[Affine<C>]) {
+        if self.ptr != 0 {
+            batch_add(self.ptr, &mut self.buckets, &self.set, bases);
+            self.ptr = 0;
+            self.set
+                .iter_mut()
+                .for_each(|sch| *sch = SchedulePoint::default());
+        }
+    }
+
+    fn add(&mut self, bases: &[Affine<C>], base_idx: usize, buck_idx: usize, sign: bool) {
+        if !self.buckets[buck_idx].assign(&bases[base_idx], sign) {
+            self.set[self.ptr] = SchedulePoint::new(base_idx, buck_idx, sign);
+            self.ptr += 1;
+        }
+
+        if self.ptr == self.set.len() {
+            self.execute(bases);
+        }
+    }
+}
+
+/// Performs a multi-scalar multiplication operation.
+///
+/// This function will panic if coeffs and bases have a different length.
+pub fn msm_serial<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C], acc: &mut C::Curve) {
+    let coeffs: Vec<_> = coeffs.iter().map(|a| a.to_repr()).collect();
+    msm_serial_bytes(&coeffs, bases, acc)
+}
+
+/// [`msm_serial`] over scalars given as little-endian bytes.
+fn msm_serial_bytes<C: CurveAffine, T: AsRef<[u8]>>(coeffs: &[T], bases: &[C], acc: &mut C::Curve) {
+    let c = default_window(bases.len());
+
+    let field_byte_size = coeffs.first().map_or(0, |coeff| coeff.as_ref().len());
+    // OR all coefficients in order to make a mask to figure out the maximum number of bytes used
+    // among all coefficients.
+    let mut acc_or = vec![0; field_byte_size];
+    for coeff in coeffs {
+        for (acc_limb, limb) in acc_or.iter_mut().zip(coeff.as_ref().iter()) {
+            *acc_limb |= *limb;
+        }
+    }
+    let max_byte_size = field_byte_size
+        - acc_or
+            .iter()
+            .rev()
+            .position(|v| *v != 0)
+            .unwrap_or(field_byte_size);
+    if max_byte_size == 0 {
+        return;
+    }
+    let number_of_windows = max_byte_size * 8_usize / c + 1;
+
+    for current_window in (0..number_of_windows).rev() {
+        for _ in 0..c {
+            *acc = acc.double();
+        }
+
+        #[derive(Clone, Copy)]
+        enum Bucket<C: CurveAffine> {
+            None,
+            Affine(C),
+            Projective(C::Curve),
+        }
+
+        impl<C: CurveAffine> Bucket<C> {
+            fn add_assign(&mut self, other: &C) {
+                *self = match *self {
+                    Bucket::None => Bucket::Affine(*other),
+                    Bucket::Affine(a) => Bucket::Projective(a + *other),
+                    Bucket::Projective(mut a) => {
+                        a += *other;
+                        Bucket::Projective(a)
+                    }
+                }
+            }
+
+            fn add(self, mut other: C::Curve) -> C::Curve {
+                match self {
+                    Bucket::None => other,
+                    Bucket::Affine(a) => {
+                        other += a;
+                        other
+                    }
+                    Bucket::Projective(a) => other + a,
+                }
+            }
+        }
+
+        let mut buckets: Vec<Bucket<C>> = vec![Bucket::None; 1 << (c - 1)];
+
+        for (coeff, base) in coeffs.iter().zip(bases.iter()) {
+            let coeff = get_booth_index(current_window, c, coeff.as_ref());
+            if coeff.is_positive() {
+                buckets[coeff as usize - 1].add_assign(base);
+            }
+            if coeff.is_negative() {
+                buckets[coeff.unsigned_abs() as usize - 1].add_assign(&base.neg());
+            }
+        }
+
+        // Summation by parts
+        // e.g. 3a + 2b + 1c = a +
+        //                    (a) + b +
+        //                    ((a) + b) + c
+        let mut running_sum = C::Curve::identity();
+        for exp in buckets.into_iter().rev() {
+            running_sum = exp.add(running_sum);
+            *acc += &running_sum;
+        }
+    }
+}
+
+/// Performs a multi-scalar multiplication operation.
+///
+/// This function will panic if coeffs and bases have a different length.
+///
+/// This will use multithreading if beneficial.
+pub fn msm_parallel<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve {
+    assert_eq!(coeffs.len(), bases.len());
+
+    let coeffs: Vec<_> = coeffs.par_iter().map(|a| a.to_repr()).collect();
+    msm_parallel_bytes(&coeffs, bases)
+}
+
+/// [`msm_parallel`] over scalars given as little-endian bytes.
+fn msm_parallel_bytes<C: CurveAffine, T: AsRef<[u8]> + Sync>(
+    coeffs: &[T],
+    bases: &[C],
+) -> C::Curve {
+    let num_threads = rayon::current_num_threads();
+    if coeffs.len() > num_threads {
+        let chunk = coeffs.len() / num_threads;
+        let num_chunks = coeffs.chunks(chunk).len();
+        let mut results = vec![C::Curve::identity(); num_chunks];
+        rayon::scope(|scope| {
+            let chunk = coeffs.len() / num_threads;
+
+            for ((coeffs, bases), acc) in coeffs
+                .chunks(chunk)
+                .zip(bases.chunks(chunk))
+                .zip(results.iter_mut())
+            {
+                scope.spawn(move |_| {
+                    msm_serial_bytes(coeffs, bases, acc);
+                });
+            }
+        });
+        results.iter().fold(C::Curve::identity(), |a, b| a + b)
+    } else {
+        let mut acc = C::Curve::identity();
+        msm_serial_bytes(coeffs, bases, &mut acc);
+        acc
+    }
+}
+
+/// This function will panic if coeffs and bases have a different length.
+///
+/// This will use multithreading if beneficial.
+///
+/// The scalars are used at full width, even for curves with an efficient
+/// endomorphism: use [`CurveMsm::msm`] to select [`msm_glv`] for those.
+pub fn msm_best<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve {
+    assert_eq!(coeffs.len(), bases.len());
+
+    let c = default_window(bases.len());
+
+    if c < 10 {
+        return msm_parallel(coeffs, bases);
+    }
+
+    // coeffs to byte representation
+    let coeffs: Vec<_> = coeffs.par_iter().map(|a| a.to_repr()).collect();
+    // copy bases into `Affine` to skip in on curve check for every access
+    let bases_local: Vec<_> = bases.par_iter().map(Affine::from).collect();
+    msm_bytes(
+        &coeffs,
+        bases,
+        &bases_local,
+        C::Scalar::NUM_BITS as usize,
+        c,
+        BATCH_SIZE,
+    )
+}
+
+/// Performs one multi-scalar multiplication operation per coefficient vector
+/// in `coeffs`, all of them over the same `bases`.
+///
+/// The conversion of the bases is done once and shared by all the jobs, and
+/// the work is scheduled across both jobs and windows.
+///
+/// This function will panic if any coefficient vector and bases have a
+/// different length.
+///
+/// This will use multithreading if beneficial.
+pub fn msm_batch<C: CurveAffine>(coeffs: &[&[C::Scalar]], bases: &[C]) -> Vec<C::Curve> {
+    for coeffs in coeffs.iter() {
+        assert_eq!(coeffs.len(), bases.len());
+    }
+
+    let c = default_window(bases.len());
+
+    if c < 10 {
+        return coeffs
+            .par_iter()
+            .map(|coeffs| msm_parallel(coeffs, bases))
+            .collect();
+    }
+
+    // coeffs to byte representation
+    let coeffs: Vec<Vec<_>> = coeffs
+        .par_iter()
+        .map(|coeffs| coeffs.par_iter().map(|a| a.to_repr()).collect())
+        .collect();
+    // copy bases into `Affine` to skip in on curve check for every access
+    let bases_local: Vec<_> = bases.par_iter().map(Affine::from).collect();
+
+    // number of windows
+    let number_of_windows = C::Scalar::NUM_BITS as usize / c + 1;
+    // accumumator for each window of each job
+    let mut acc = vec![C::Curve::identity(); coeffs.len() * number_of_windows];
+    acc.par_iter_mut().enumerate().for_each(|(i, acc)| {
+        let (job, w) = (i / number_of_windows, i % number_of_windows);
+        *acc = msm_window(&coeffs[job], bases, &bases_local, c, w, BATCH_SIZE);
+
+        // shift accumulator to the window position
+        for _ in 0..c * w {
+            *acc = acc.double();
+        }
+    });
+    acc.chunks(number_of_windows)
+        .map(|acc| acc.iter().sum())
+        .collect()
+}
+
+/// Performs a multi-scalar multiplication operation, specialised for
+/// coefficient vectors dominated by zeros, ones and small values such as
+/// witness commitments.
+///
+/// The scalars are classified first: zeros are skipped, bases with a
+/// coefficient of one are summed directly, scalars fitting into a `u64` are
+/// handled by a short-window pass limited to their actual bit width, and only
+/// the remaining ones go through the full-width [`msm_best`].
+///
+/// This function will panic if coeffs and bases have a different length.
+///
+/// This will use multithreading if beneficial.
+pub fn msm_sparse<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve {
+    assert_eq!(coeffs.len(), bases.len());
+
+    #[derive(Clone, Copy)]
+    enum Class {
+        Zero,
+        One,
+        Small(u64),
+        Full,
+    }
+
+    let classes: Vec<_> = coeffs
+        .par_iter()
+        .zip(bases.par_iter())
+        .map(|(coeff, base)| {
+            if bool::from(base.is_identity()) {
+                return Class::Zero;
+            }
+            let repr = coeff.to_repr();
+            let repr = repr.as_ref();
+            if repr[8..].iter().any(|b| *b != 0) {
+                return Class::Full;
+            }
+            let mut small = [0u8; 8];
+            small.copy_from_slice(&repr[..8]);
+            match u64::from_le_bytes(small) {
+                0 => Class::Zero,
+                1 => Class::One,
+                v => Class::Small(v),
+            }
+        })
+        .collect();
+
+    let mut ones = vec![];
+    let mut small_coeffs = vec![];
+    let mut small_bases = vec![];
+    let mut full_coeffs = vec![];
+    let mut full_bases = vec![];
+    for ((class, coeff), base) in classes.iter().zip(coeffs.iter()).zip(bases.iter()) {
+        match class {
+            Class::Zero => {}
+            Class::One => ones.push(*base),
+            Class::Small(v) => {
+                small_coeffs.push(*v);
+                small_bases.push(*base);
+            }
+            Class::Full => {
+                full_coeffs.push(*coeff);
+                full_bases.push(*base);
+            }
+        }
+    }
+
+    let acc_ones = sum_bases(&ones);
+
+    let acc_small = msm_u64(&small_coeffs, &small_bases);
+
+    acc_ones + acc_small + msm_best(&full_coeffs, &full_bases)
+}
+
+/// Sums the bases.
+fn sum_bases<C: CurveAffine>(bases: &[C]) -> C::Curve {
+    bases
+        .par_iter()
+        .fold(C::Curve::identity, |acc, base| acc + base)
+        .reduce(C::Curve::identity, |a, b| a + b)
+}
+
+/// Performs a multi-scalar multiplication operation with `u64` coefficients,
+/// such as lookup multiplicities or range-check witnesses.
+///
+/// The coefficients are used directly, without going through `C::Scalar`,
+/// and windows are only run over the bits used by the largest coefficient.
+///
+/// This function will panic if coeffs and bases have a different length.
+///
+/// This will use multithreading if beneficial.
+pub fn msm_u64<C: CurveAffine>(coeffs: &[u64], bases: &[C]) -> C::Curve {
+    assert_eq!(coeffs.len(), bases.len());
+
+    let num_bits = 64 - coeffs.iter().fold(0, |acc, v| acc | v).leading_zeros() as usize;
+    if num_bits == 0 {
+        return C::Curve::identity();
+    }
+
+    let coeffs: Vec<_> = coeffs.par_iter().map(|v| v.to_le_bytes()).collect();
+
+    let c = default_window(bases.len());
+
+    if c < 10 {
+        // `msm_serial` skips the unused high bytes
+        return msm_parallel_bytes(&coeffs, bases);
+    }
+
+    // copy bases into `Affine` to skip in on curve check for every access
+    let bases_local: Vec<_> = bases.par_iter().map(Affine::from).collect();
+    msm_bytes(&coeffs, bases, &bases_local, num_bits, c, BATCH_SIZE)
+}
+
+/// Performs a multi-scalar multiplication operation with boolean
+/// coefficients, that is sums the bases whose coefficient is `true`.
+///
+/// This function will panic if coeffs and bases have a different length.
+///
+/// This will use multithreading if beneficial.
+pub fn msm_bits<C: CurveAffine>(coeffs: &[bool], bases: &[C]) -> C::Curve {
+    assert_eq!(coeffs.len(), bases.len());
+
+    coeffs
+        .par_iter()
+        .zip(bases.par_iter())
+        .filter(|(coeff, _)| **coeff)
+        .fold(C::Curve::identity, |acc, (_, base)| acc + base)
+        .reduce(C::Curve::identity, |a, b| a + b)
+}
+
+/// Performs a multi-scalar multiplication operation using the GLV
+/// endomorphism of the curve.
+///
+/// Every scalar $k$ is decomposed into two ~128-bit halves $k_1$, $k_2$ with
+/// $k = k_1 - \zeta k_2$, and the MSM is then run over the bases together with
+/// their endomorphism images, which halves the number of windows.
+///
+/// This function will panic if coeffs and bases have a different length.
+///
+/// This will use multithreading if beneficial.
+pub fn msm_glv<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve
+where
+    C::CurveExt: CurveEndo,
+{
+    assert_eq!(coeffs.len(), bases.len());
+
+    let decomposed: Vec<_> = coeffs
+        .par_iter()
+        .map(C::CurveExt::decompose_scalar)
+        .collect();
+
+    // signed bases and endomorphism images
+    let bases_proj: Vec<_> = bases
+        .par_iter()
+        .zip(decomposed.par_iter())
+        .flat_map_iter(|(base, (_, k1_neg, _, k2_neg))| {
+            let base = base.to_curve();
+            let endo = base.endo();
+            [
+                if *k1_neg { -base } else { base },
+                if *k2_neg { endo } else { -endo },
+            ]
+        })
+        .collect();
+    let mut bases_glv = vec![C::identity(); bases_proj.len()];
+    C::Curve::batch_normalize(&bases_proj, &mut bases_glv);
+
+    let c = default_window(bases_glv.len());
+
+    if c < 10 {
+        let coeffs_glv: Vec<_> = decomposed
+            .iter()
+            .flat_map(|(k1, _, k2, _)| [C::Scalar::from_u128(*k1), C::Scalar::from_u128(*k2)])
+            .collect();
+        return msm_parallel(&coeffs_glv, &bases_glv);
+    }
+
+    let coeffs_glv: Vec<_> = decomposed
+        .iter()
+        .flat_map(|(k1, _, k2, _)| [k1.to_le_bytes(), k2.to_le_bytes()])
+        .collect();
+    let bases_local: Vec<_> = bases_glv.par_iter().map(Affine::from).collect();
+    msm_bytes(&coeffs_glv, &bases_glv, &bases_local, 128, c, BATCH_SIZE)
+}
+
+/// Selects the fastest multi-scalar multiplication strategy known for a curve:
+/// [`msm_glv`] for curves implementing [`CurveEndo`], [`msm_best`] otherwise.
+pub trait CurveMsm: CurveAffine {
+    /// Performs a multi-scalar multiplication operation.
+    ///
+    /// This function will panic if coeffs and bases have a different length.
+    ///
+    /// This will use multithreading if beneficial.
+    fn msm(coeffs: &[Self::Scalar], bases: &[Self]) -> Self::Curve {
+        msm_best(coeffs, bases)
+    }
+}
+
+/// Multi-scalar multiplication with batch-affine buckets over scalars given
+/// as little-endian bytes of at most `num_bits` bits, using windows of `c` bits
+/// and batches of `batch_size` affine additions.
+fn msm_bytes<C: CurveAffine, T: AsRef<[u8]> + Sync>(
+    coeffs: &[T],
+    bases: &[C],
+    bases_local: &[Affine<C>],
+    num_bits: usize,
+    c: usize,
+    batch_size: usize,
+) -> C::Curve {
+    // number of windows
+    let number_of_windows = num_bits / c + 1;
+    // accumumator for each window
+    let mut acc = vec![C::Curve::identity(); number_of_windows];
+    acc.par_iter_mut().enumerate().rev().for_each(|(w, acc)| {
+        *acc = msm_window(coeffs, bases, bases_local, c, w, batch_size);
+
+        // shift accumulator to the window position
+        for _ in 0..c * w {
+            *acc = acc.double();
+        }
+    });
+    acc.into_iter().sum::<_>()
+}
+
+/// Computes the (unshifted) sum of the bucket contents for window `w`.
+fn msm_window<C: CurveAffine, T: AsRef<[u8]>>(
+    coeffs: &[T],
+    bases: &[C],
+    bases_local: &[Affine<C>],
+    c: usize,
+    w: usize,
+    batch_size: usize,
+) -> C::Curve {
+    // jacobian buckets for already scheduled points
+    let mut j_bucks = vec![Bucket::<C>::None; 1 << (c - 1)];
+
+    // schedular for affine addition
+    let mut sched = Schedule::new(c, batch_size);
+
+    for (base_idx, coeff) in coeffs.iter().enumerate() {
+        let buck_idx = get_booth_index(w, c, coeff.as_ref());
+
+        // batch addition assumes bases are never the identity
+        if buck_idx != 0 && !bases_local[base_idx].is_identity() {
+            // parse bucket index
+            let sign = buck_idx.is_positive();
+            let buck_idx = buck_idx.unsigned_abs() as usize - 1;
+
+            if sched.contains(buck_idx) {
+                // greedy accumulation
+                // we use original bases here
+                j_bucks[buck_idx].add_assign(&bases[base_idx], sign);
+            } else {
+                // also flushes the schedule if full
+                sched.add(bases_local, base_idx, buck_idx, sign);
+            }
+        }
+    }
+
+    // flush the schedule
+    sched.execute(bases_local);
+
+    // summation by parts
+    // e.g. 3a + 2b + 1c = a +
+    //                    (a) + b +
+    //                    ((a) + b) + c
+    let mut acc = C::Curve::identity();
+    let mut running_sum = C::Curve::identity();
+    for (j_buck, a_buck) in j_bucks.iter().zip(sched.buckets.iter()).rev() {
+        running_sum += j_buck.add(a_buck);
+        acc += running_sum;
+    }
+    acc
+}
+
+#[cfg(test)]
+mod test {
+    use std::ops::Neg;
+
+    use crate::bn256::{Fr, G1Affine, G1};
+    use ark_std::{end_timer, start_timer};
+    use ff::{Field, PrimeField};
+    use group::{Curve, Group};
+    use pasta_curves::arithmetic::CurveAffine;
+    use rand_core::{OsRng, RngCore};
+
+    #[test]
+    fn test_booth_encoding() {
+        fn mul(scalar: &Fr, point: &G1Affine, window: usize) -> G1Affine {
+            let u = scalar.to_repr();
+            let n = Fr::NUM_BITS as usize / window + 1;
+
+            let table = (0..=1 << (window - 1))
+                .map(|i| point * Fr::from(i as u64))
+                .collect::<Vec<_>>();
+
+            let mut acc = G1::identity();
+            for i in (0..n).rev() {
+                for _ in 0..window {
+                    acc = acc.double();
+                }
+
+                let idx = super::get_booth_index(i, window, u.as_ref());
+
+                if idx.is_negative() {
+                    acc += table[idx.unsigned_abs() as usize].neg();
+                }
+                if idx.is_positive() {
+                    acc += table[idx.unsigned_abs() as usize];
+                }
+            }
+
+            acc.to_affine()
+        }
+
+        let (scalars, points): (Vec<_>, Vec<_>) = (0..10)
+            .map(|_| {
+                let scalar = Fr::random(OsRng);
+                let point = G1Affine::random(OsRng);
+                (scalar, point)
+            })
+            .unzip();
+
+        for window in 1..10 {
+            for (scalar, point) in scalars.iter().zip(points.iter()) {
+                let c0 = mul(scalar, point, window);
+                let c1 = point * scalar;
+                assert_eq!(c0, c1.to_affine());
+            }
+        }
+    }
+
+    #[test]
+    fn test_booth_max_window() {
+        for window in [super::MAX_WINDOW - 1, super::MAX_WINDOW] {
+            let n = Fr::NUM_BITS as usize / window + 1;
+            let radix = Fr::from(2).pow_vartime([window as u64]);
+            for scalar in [Fr::ZERO, Fr::ONE, -Fr::ONE, Fr::random(OsRng)] {
+                let u = scalar.to_repr();
+                let acc = (0..n).rev().fold(Fr::ZERO, |acc, i| {
+                    let idx = super::get_booth_index(i, window, u.as_ref());
+                    let digit = Fr::from(idx.unsigned_abs() as u64);
+                    acc * radix + if idx.is_negative() { -digit } else { digit }
+                });
+                assert_eq!(acc, scalar);
+            }
+        }
+    }
+
+    fn run_msm_cross<C: CurveAffine>(min_k: usize, max_k: usize) {
+        use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+        let points = (0..1 << max_k)
+            .into_par_iter()
+            .map(|_| C::Curve::random(OsRng))
+            .collect::<Vec<_>>();
+        let mut affine_points = vec![C::identity(); 1 << max_k];
+        C::Curve::batch_normalize(&points[..], &mut affine_points[..]);
+        let points = affine_points;
+
+        let scalars = (0..1 << max_k)
+            .into_par_iter()
+            .map(|_| C::Scalar::random(OsRng))
+            .collect::<Vec<_>>();
+
+        for k in min_k..=max_k {
+            let points = &points[..1 << k];
+            let scalars = &scalars[..1 << k];
+
+            let t0 = start_timer!(|| format!("cyclone indep k={}", k));
+            let e0 = super::msm_best(scalars, points);
+            end_timer!(t0);
+
+            let t1 = start_timer!(|| format!("older k={}", k));
+            let e1 = super::msm_parallel(scalars, points);
+            end_timer!(t1);
+            assert_eq!(e0, e1);
+        }
+    }
+
+    #[test]
+    fn test_msm_cross() {
+        run_msm_cross::<G1Affine>(14, 18);
+    }
+
+    fn run_msm_glv<C: super::CurveMsm>(ks: &[usize])
+    where
+        C::CurveExt: crate::CurveEndo,
+    {
+        let max_k = *ks.iter().max().unwrap();
+        let points = (0..1 << max_k)
+            .map(|_| C::Curve::random(OsRng))
+            .collect::<Vec<_>>();
+        let mut bases = vec![C::identity(); 1 << max_k];
+        C::Curve::batch_normalize(&points[..], &mut bases[..]);
+        let scalars = (0..1 << max_k)
+            .map(|_| C::Scalar::random(OsRng))
+            .collect::<Vec<_>>();
+
+        for k in ks {
+            let bases = &bases[..1 << k];
+            let scalars = &scalars[..1 << k];
+
+            let mut expected = C::Curve::identity();
+            super::msm_serial(scalars, bases, &mut expected);
+            assert_eq!(super::msm_glv(scalars, bases), expected);
+            assert_eq!(C::msm(scalars, bases), expected);
+        }
+    }
+
+    /// Bases with identities, repetitions and negations, to hit the doubling
+    /// and cancellation cases of the batch addition.
+    fn edge_case_bases<C: CurveAffine>(k: usize) -> Vec<C> {
+        let g = C::Curve::random(OsRng);
+        let points = std::iter::successors(Some(g), |p| Some(*p + g))
+            .take(1 << k)
+            .collect::<Vec<_>>();
+        let mut bases = vec![C::identity(); 1 << k];
+        C::Curve::batch_normalize(&points[..], &mut bases[..]);
+        for i in (0..bases.len()).step_by(5) {
+            bases[i] = match i % 3 {
+                0 => C::identity(),
+                1 => bases[1],
+                _ => -bases[1],
+            };
+        }
+        bases
+    }
+
+    fn run_msm_edge_cases<C: CurveAffine>(k: usize, config: &super::MsmConfig) {
+        let bases = edge_case_bases::<C>(k);
+        let scalars = (0..1 << k)
+            .map(|i| {
+                if i % 2 == 0 {
+                    C::Scalar::from(3)
+                } else {
+                    C::Scalar::random(OsRng)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut expected = C::Curve::identity();
+        super::msm_serial(&scalars, &bases, &mut expected);
+        assert_eq!(super::msm_with_config(&scalars, &bases, config), expected);
+        if super::default_window(bases.len()) >= 10 {
+            assert_eq!(super::msm_best(&scalars, &bases), expected);
+        }
+    }
+
+    #[test]
+    fn test_msm_g2() {
+        let config = super::MsmConfig::new().with_window(10);
+        run_msm_edge_cases::<crate::bn256::G2Affine>(13, &config);
+        run_msm_edge_cases::<crate::bls12381::G2Affine>(13, &config);
+        run_msm_edge_cases::<crate::pluto_eris::G2Affine>(8, &config);
+    }
+
+    #[test]
+    fn test_msm_edge_cases() {
+        let config = super::MsmConfig::new().with_window(4);
+        run_msm_edge_cases::<G1Affine>(13, &config);
+        // a = -3
+        run_msm_edge_cases::<crate::secp256r1::Secp256r1Affine>(13, &config);
+    }
+
+    #[test]
+    fn test_batch_add_same_x() {
+        use crate::bn256::G2;
+
+        let p = G2::random(OsRng).to_affine();
+        let bases = [super::Affine::from(&p), super::Affine::from(&-p)];
+        let double = super::Affine::from(&(p + p).to_affine());
+
+        // P + P and P - (-P)
+        for schedule in [
+            super::SchedulePoint::new(0, 0, true),
+            super::SchedulePoint::new(1, 0, false),
+        ] {
+            let mut buckets = vec![super::BucketAffine::Point(bases[0])];
+            super::batch_add(1, &mut buckets, &[schedule], &bases);
+            assert_eq!(buckets[0].x(), double.x);
+            assert_eq!(buckets[0].y(), double.y);
+        }
+
+        // P + (-P) and P - P
+        for schedule in [
+            super::SchedulePoint::new(1, 0, true),
+            super::SchedulePoint::new(0, 0, false),
+        ] {
+            let mut buckets = vec![super::BucketAffine::Point(bases[0])];
+            super::batch_add(1, &mut buckets, &[schedule], &bases);
+            assert!(buckets[0].is_inf());
+        }
+    }
+
+    #[test]
+    fn test_msm_same_x() {
+        use crate::bn256::{G2Affine, G2};
+
+        let p = G2::random(OsRng).to_affine();
+        let q = G2::random(OsRng).to_affine();
+        let s = Fr::random(OsRng);
+        let t = Fr::random(OsRng);
+
+        // Duplicated bases and bases next to their negation make every window
+        // double or cancel a bucket in the batch addition.
+        for (bases, coeffs) in [
+            (vec![p, p], vec![s, s]),
+            (vec![p, -p], vec![s, s]),
+            (vec![p, p], vec![s, -s]),
+            (vec![p, p, -p, q, q, -q], vec![s, s, t, t, s, s]),
+        ] {
+            let mut expected = G2::identity();
+            super::msm_serial(&coeffs, &bases, &mut expected);
+
+            let coeffs: Vec<_> = coeffs.iter().map(|a| a.to_repr()).collect();
+            let bases_local: Vec<_> = bases.iter().map(super::Affine::from).collect();
+            for c in [1, 4, 8] {
+                let result = super::msm_bytes::<G2Affine, _>(
+                    &coeffs,
+                    &bases,
+                    &bases_local,
+                    Fr::NUM_BITS as usize,
+                    c,
+                    super::BATCH_SIZE,
+                );
+                assert_eq!(result, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_msm_batch() {
+        let max_k = 13;
+        let points = (0..1 << max_k)
+            .map(|_| G1::random(OsRng))
+            .collect::<Vec<_>>();
+        let mut bases = vec![G1Affine::default(); 1 << max_k];
+        G1::batch_normalize(&points[..], &mut bases[..]);
+        let scalars = (0..3)
+            .map(|_| {
+                (0..1 << max_k)
+                    .map(|_| Fr::random(OsRng))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        for k in [0, 3, 8, max_k] {
+            let bases = &bases[..1 << k];
+            let coeffs = scalars.iter().map(|s| &s[..1 << k]).collect::<Vec<_>>();
+            let results = super::msm_batch(&coeffs, bases);
+            assert_eq!(results.len(), coeffs.len());
+            for (coeffs, result) in coeffs.iter().zip(results) {
+                assert_eq!(result, super::msm_best(coeffs, bases));
+            }
+        }
+        assert!(super::msm_batch::<G1Affine>(&[], &bases).is_empty());
+    }
+
+    #[test]
+    fn test_msm_sparse() {
+        let max_k = 13;
+        let points = (0..1 << max_k)
+            .map(|_| G1::random(OsRng))
+            .collect::<Vec<_>>();
+        let mut bases = vec![G1Affine::default(); 1 << max_k];
+        G1::batch_normalize(&points[..], &mut bases[..]);
+
+        let scalars = (0..1 << max_k)
+            .map(|i| match i % 7 {
+                0..=2 => Fr::ZERO,
+                3 => Fr::ONE,
+                4 => Fr::from(OsRng.next_u64() >> (i % 60)),
+                5 => Fr::from(u64::MAX),
+                _ => Fr::random(OsRng),
+            })
+            .collect::<Vec<_>>();
+
+        for k in [0, 3, 8, max_k] {
+            let bases = &bases[..1 << k];
+            let scalars = &scalars[..1 << k];
+            let expected = super::msm_best(scalars, bases);
+            assert_eq!(super::msm_sparse(scalars, bases), expected);
+
+            // only small scalars
+            let small = scalars
+                .iter()
+                .map(|s| if *s == Fr::ONE { Fr::from(2) } else { *s })
+                .map(|s| {
+                    if s.to_repr()[8..].iter().any(|b| *b != 0) {
+                        Fr::from(5)
+                    } else {
+                        s
+                    }
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                super::msm_sparse(&small, bases),
+                super::msm_best(&small, bases)
+            );
+        }
+
+        let zeros = vec![Fr::ZERO; 1 << 4];
+        assert_eq!(super::msm_sparse(&zeros, &bases[..1 << 4]), G1::identity());
+    }
+
+    #[test]
+    fn test_msm_u64() {
+        let max_k = 13;
+        let points = (0..1 << max_k)
+            .map(|_| G1::random(OsRng))
+            .collect::<Vec<_>>();
+        let mut bases = vec![G1Affine::default(); 1 << max_k];
+        G1::batch_normalize(&points[..], &mut bases[..]);
+        bases[1] = G1Affine::default();
+
+        for bits in [1, 8, 17, 64] {
+            let coeffs = (0..1 << max_k)
+                .map(|_| OsRng.next_u64() >> (64 - bits))
+                .collect::<Vec<_>>();
+            let flags = coeffs.iter().map(|v| v & 1 == 1).collect::<Vec<_>>();
+
+            for k in [0, 3, 8, max_k] {
+                let bases = &bases[..1 << k];
+                let coeffs = &coeffs[..1 << k];
+                let scalars = coeffs.iter().map(|v| Fr::from(*v)).collect::<Vec<_>>();
+                assert_eq!(
+                    super::msm_u64(coeffs, bases),
+                    super::msm_best(&scalars, bases)
+                );
+
+                let flags = &flags[..1 << k];
+                let scalars = flags
+                    .iter()
+                    .map(|b| Fr::from(*b as u64))
+                    .collect::<Vec<_>>();
+                assert_eq!(
+                    super::msm_bits(flags, bases),
+                    super::msm_best(&scalars, bases)
+                );
+            }
+        }
+
+        let zeros = vec![0; 1 << 4];
+        assert_eq!(super::msm_u64(&zeros, &bases[..1 << 4]), G1::identity());
+    }
+
+    #[test]
+    fn test_msm_glv() {
+        run_msm_glv::<G1Affine>(&[0, 1, 3, 8, 12]);
+        run_msm_glv::<crate::grumpkin::G1Affine>(&[3, 8]);
+        run_msm_glv::<crate::bls12381::G1Affine>(&[3, 8]);
+        run_msm_glv::<crate::bls12381::G2Affine>(&[3, 6]);
+        run_msm_glv::<crate::pasta::EqAffine>(&[3, 8]);
+        run_msm_glv::<crate::pasta::EpAffine>(&[3, 8]);
+        run_msm_glv::<crate::secp256k1::Secp256k1Affine>(&[3, 8]);
+    }
+}
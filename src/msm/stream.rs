@@ -0,0 +1,133 @@
+use std::io::{self, Read};
+
+use group::Group;
+
+use super::msm_best;
+use crate::serde::SerdeObject;
+use crate::CurveAffine;
+
+/// Performs a multi-scalar multiplication operation over bases produced by an
+/// iterator, which are consumed `chunk_size` at a time.
+///
+/// Only one chunk of bases is held in memory at any time, so the bases can be
+/// generated or decoded on the fly instead of being fully materialized.
+///
+/// This function will panic if `chunk_size` is zero or if `bases` yields fewer
+/// elements than `coeffs`. Extra bases are ignored.
+///
+/// This will use multithreading if beneficial.
+pub fn msm_chunked<C: CurveAffine, I: IntoIterator<Item = C>>(
+    coeffs: &[C::Scalar],
+    bases: I,
+    chunk_size: usize,
+) -> C::Curve {
+    assert!(chunk_size > 0, "chunk size must be positive");
+
+    let mut bases = bases.into_iter();
+    let mut chunk = Vec::with_capacity(chunk_size.min(coeffs.len()));
+    let mut acc = C::Curve::identity();
+    for coeffs in coeffs.chunks(chunk_size) {
+        chunk.clear();
+        chunk.extend(bases.by_ref().take(coeffs.len()));
+        assert_eq!(chunk.len(), coeffs.len(), "not enough bases");
+        acc += msm_best(coeffs, &chunk);
+    }
+    acc
+}
+
+/// Performs a multi-scalar multiplication operation over bases read from
+/// `reader`, in the raw format written by [`SerdeObject::write_raw`].
+///
+/// The bases are read `chunk_size` at a time with
+/// [`SerdeObject::read_raw_unchecked`], so that peak memory is bounded by the
+/// chunk size rather than by the number of bases. As for any unchecked read,
+/// the data must come from a trusted source such as a locally stored SRS.
+///
+/// Exactly `coeffs.len()` bases are read. An error is returned if the reader
+/// fails or ends before that.
+///
+/// This function will panic if `chunk_size` is zero.
+///
+/// This will use multithreading if beneficial.
+pub fn msm_read<C: CurveAffine + SerdeObject, R: Read>(
+    coeffs: &[C::Scalar],
+    reader: &mut R,
+    chunk_size: usize,
+) -> io::Result<C::Curve> {
+    assert!(chunk_size > 0, "chunk size must be positive");
+
+    let point_size = C::identity().to_raw_bytes().len();
+    let mut buf = vec![0u8; point_size * chunk_size.min(coeffs.len())];
+    let mut chunk = Vec::with_capacity(chunk_size.min(coeffs.len()));
+    let mut acc = C::Curve::identity();
+    for coeffs in coeffs.chunks(chunk_size) {
+        let buf = &mut buf[..point_size * coeffs.len()];
+        reader.read_exact(buf)?;
+
+        let mut buf = &buf[..];
+        chunk.clear();
+        chunk.extend((0..coeffs.len()).map(|_| C::read_raw_unchecked(&mut buf)));
+        acc += msm_best(coeffs, &chunk);
+    }
+    Ok(acc)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use ff::Field;
+    use group::{Curve, Group};
+    use rand_core::OsRng;
+
+    use super::{msm_chunked, msm_read};
+    use crate::bn256::{Fr, G1Affine, G1};
+    use crate::msm::msm_best;
+    use crate::serde::SerdeObject;
+
+    #[test]
+    fn test_msm_stream() {
+        let n = 100;
+        let points = (0..n).map(|_| G1::random(OsRng)).collect::<Vec<_>>();
+        let mut bases = vec![G1Affine::default(); n];
+        G1::batch_normalize(&points[..], &mut bases[..]);
+        let scalars = (0..n).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+        let expected = msm_best(&scalars, &bases);
+
+        let mut raw = vec![];
+        for base in bases.iter() {
+            base.write_raw(&mut raw).unwrap();
+        }
+
+        for chunk_size in [1, 7, n, 2 * n] {
+            assert_eq!(
+                msm_chunked(&scalars, bases.iter().cloned(), chunk_size),
+                expected
+            );
+            let mut reader = Cursor::new(&raw);
+            assert_eq!(
+                msm_read::<G1Affine, _>(&scalars, &mut reader, chunk_size).unwrap(),
+                expected
+            );
+            assert_eq!(reader.position() as usize, raw.len());
+        }
+
+        // fewer coefficients than bases
+        assert_eq!(
+            msm_read::<G1Affine, _>(&scalars[..10], &mut Cursor::new(&raw), 3).unwrap(),
+            msm_best(&scalars[..10], &bases[..10])
+        );
+
+        // truncated input
+        let err = msm_read::<G1Affine, _>(&scalars, &mut Cursor::new(&raw[..raw.len() - 1]), 7)
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    #[should_panic(expected = "not enough bases")]
+    fn test_msm_chunked_short() {
+        let bases = vec![G1Affine::generator(); 3];
+        msm_chunked(&[Fr::ONE; 4], bases, 2);
+    }
+}
@@ -38,6 +38,9 @@ const ENDO_PARAMS_EP: EndoParameters = EndoParameters {
 endo!(Eq, Fp, ENDO_PARAMS_EQ);
 endo!(Ep, Fq, ENDO_PARAMS_EP);
 
+crate::impl_curve_msm!(EqAffine, "glv");
+crate::impl_curve_msm!(EpAffine, "glv");
+
 #[test]
 fn test_endo() {
     use ff::Field;
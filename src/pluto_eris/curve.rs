@@ -130,6 +130,8 @@ new_curve_impl!(
     standard_sign
 );
 
+crate::impl_curve_msm!(G1Affine);
+
 impl group::cofactor::CofactorGroup for Eris {
     type Subgroup = Eris;
 
@@ -152,11 +154,16 @@ impl G1 {
     const SVDW_Z: Fp = Fp::ONE;
 
     fn default_hash_to_curve_suite() -> crate::hash_to_curve::Suite<Self, sha2::Sha256, 72> {
-        crate::hash_to_curve::Suite::<Self, sha2::Sha256, 72>::new(
-            b"pluto_XMD:SHA-256_SVDW_RO_",
-            Self::SVDW_Z,
-            crate::hash_to_curve::Method::SVDW,
-        )
+        crate::hash_to_curve::Suite::pluto_svdw(b"pluto_XMD:SHA-256_SVDW_RO_")
+    }
+}
+
+impl<D: crate::hash_to_curve::ExpandMessage> crate::hash_to_curve::Suite<G1, D, 72> {
+    /// The Shallue-van de Woestijne suite of Pluto, under the domain separation
+    /// tag `domain`, e.g. `pluto_XMD:SHA-256_SVDW_RO_` with `D = Sha256`.
+    pub fn pluto_svdw(domain: &[u8]) -> Self {
+        Self::new(domain, G1::SVDW_Z, crate::hash_to_curve::Method::SVDW)
+            .expect("Pluto SVDW parameters are valid")
     }
 }
 
@@ -175,6 +182,8 @@ new_curve_impl!(
     standard_sign
 );
 
+crate::impl_curve_msm!(ErisAffine);
+
 impl CofactorGroup for G2 {
     type Subgroup = G2;
 
@@ -234,11 +243,16 @@ impl Eris {
     const SVDW_Z: Fq = Fq::ONE;
 
     fn default_hash_to_curve_suite() -> crate::hash_to_curve::Suite<Self, sha2::Sha256, 72> {
-        crate::hash_to_curve::Suite::<Eris, sha2::Sha256, 72>::new(
-            b"eris_XMD:SHA-256_SVDW_RO_",
-            Self::SVDW_Z,
-            crate::hash_to_curve::Method::SVDW,
-        )
+        crate::hash_to_curve::Suite::eris_svdw(b"eris_XMD:SHA-256_SVDW_RO_")
+    }
+}
+
+impl<D: crate::hash_to_curve::ExpandMessage> crate::hash_to_curve::Suite<Eris, D, 72> {
+    /// The Shallue-van de Woestijne suite of Eris, under the domain separation
+    /// tag `domain`, e.g. `eris_XMD:SHA-256_SVDW_RO_` with `D = Sha256`.
+    pub fn eris_svdw(domain: &[u8]) -> Self {
+        Self::new(domain, Eris::SVDW_Z, crate::hash_to_curve::Method::SVDW)
+            .expect("Eris SVDW parameters are valid")
     }
 }
 
@@ -269,6 +283,8 @@ new_curve_impl!(
     standard_sign
 );
 
+crate::impl_curve_msm!(G2Affine);
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -14,6 +14,7 @@ impl_field!(
 );
 
 crate::extend_field_legendre!(Fq);
+crate::impl_extension_degree!(Fq, 1);
 crate::impl_binops_calls!(Fq);
 crate::impl_binops_additive!(Fq, Fq);
 crate::impl_binops_multiplicative!(Fq, Fq);
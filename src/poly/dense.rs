@@ -0,0 +1,429 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use ff::PrimeField;
+use rayon::iter::{
+    IndexedParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator,
+};
+use rayon::slice::ParallelSlice;
+
+use super::Evaluations;
+use crate::fft::{best_fft, EvaluationDomain};
+
+/// Smallest product of the sizes of two polynomials multiplied with FFTs.
+const FFT_MUL_MIN_SIZE: usize = 1 << 10;
+
+/// Evaluates the polynomial with coefficients `coeffs`, lowest degree first,
+/// at `x` with Horner's rule.
+fn horner<F: PrimeField>(coeffs: &[F], x: F) -> F {
+    coeffs.iter().rev().fold(F::ZERO, |acc, c| acc * x + c)
+}
+
+/// A polynomial in coefficient form, with `coeffs[i]` the coefficient of
+/// $X^i$. The coefficients never have trailing zeros, so that the zero
+/// polynomial has no coefficients.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DensePolynomial<F> {
+    coeffs: Vec<F>,
+}
+
+impl<F: PrimeField> DensePolynomial<F> {
+    /// Returns the zero polynomial.
+    pub fn zero() -> Self {
+        Self { coeffs: vec![] }
+    }
+
+    /// Returns the polynomial with coefficients `coeffs`, lowest degree first.
+    pub fn from_coefficients_vec(coeffs: Vec<F>) -> Self {
+        let mut poly = Self { coeffs };
+        poly.truncate_leading_zeros();
+        poly
+    }
+
+    /// Returns the polynomial with coefficients `coeffs`, lowest degree first.
+    pub fn from_coefficients_slice(coeffs: &[F]) -> Self {
+        Self::from_coefficients_vec(coeffs.to_vec())
+    }
+
+    fn truncate_leading_zeros(&mut self) {
+        while self
+            .coeffs
+            .last()
+            .map_or(false, |c| bool::from(c.is_zero()))
+        {
+            self.coeffs.pop();
+        }
+    }
+
+    /// Returns the coefficients, lowest degree first.
+    pub fn coeffs(&self) -> &[F] {
+        &self.coeffs
+    }
+
+    /// Returns the coefficients, lowest degree first.
+    pub fn into_coeffs(self) -> Vec<F> {
+        self.coeffs
+    }
+
+    /// Returns `true` if this is the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// Returns the degree of the polynomial, zero for the zero polynomial.
+    pub fn degree(&self) -> usize {
+        self.coeffs.len().saturating_sub(1)
+    }
+
+    /// Evaluates the polynomial at `x` with Horner's rule.
+    ///
+    /// This will use multithreading if beneficial.
+    pub fn evaluate(&self, x: F) -> F {
+        let num_threads = rayon::current_num_threads();
+        if self.coeffs.len() < num_threads * 64 {
+            return horner(&self.coeffs, x);
+        }
+
+        // Horner's rule on every chunk, shifted by the power of x of its start
+        let chunk = (self.coeffs.len() + num_threads - 1) / num_threads;
+        let x_chunk = x.pow_vartime([chunk as u64]);
+        let partial: Vec<_> = self
+            .coeffs
+            .par_chunks(chunk)
+            .map(|coeffs| horner(coeffs, x))
+            .collect();
+        horner(&partial, x_chunk)
+    }
+
+    /// Evaluates the polynomial at every point of `points`.
+    ///
+    /// This will use multithreading if beneficial.
+    pub fn evaluate_batch(&self, points: &[F]) -> Vec<F> {
+        points
+            .par_iter()
+            .map(|x| horner(&self.coeffs, *x))
+            .collect()
+    }
+
+    /// Returns the evaluations of the polynomial on `domain`.
+    ///
+    /// This function will panic if the polynomial has more coefficients than
+    /// the size of the domain.
+    pub fn evaluate_over_domain(self, domain: &EvaluationDomain<F>) -> Evaluations<'_, F> {
+        assert!(
+            self.coeffs.len() <= domain.size(),
+            "polynomial larger than the domain"
+        );
+        let mut evals = self.coeffs;
+        evals.resize(domain.size(), F::ZERO);
+        domain.fft(&mut evals);
+        Evaluations::from_vec_and_domain(evals, domain)
+    }
+
+    /// Returns the quotient and the remainder of the division by the
+    /// vanishing polynomial $Z_H(X) = X^n - 1$ of `domain`.
+    pub fn divide_by_vanishing_poly(&self, domain: &EvaluationDomain<F>) -> (Self, Self) {
+        let n = domain.size();
+        if self.coeffs.len() <= n {
+            return (Self::zero(), self.clone());
+        }
+
+        // X^i = X^(i - n) Z_H(X) + X^(i - n)
+        let mut remainder = self.coeffs.clone();
+        let mut quotient = vec![F::ZERO; self.coeffs.len() - n];
+        for i in (n..self.coeffs.len()).rev() {
+            let c = remainder[i];
+            quotient[i - n] += c;
+            remainder[i - n] += c;
+        }
+        remainder.truncate(n);
+
+        (
+            Self::from_coefficients_vec(quotient),
+            Self::from_coefficients_vec(remainder),
+        )
+    }
+
+    /// Returns the quotient and the remainder of the Euclidean division by
+    /// `divisor`.
+    ///
+    /// This function will panic if `divisor` is zero.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(!divisor.is_zero(), "division by the zero polynomial");
+        if self.coeffs.len() < divisor.coeffs.len() {
+            return (Self::zero(), self.clone());
+        }
+
+        let d = divisor.coeffs.len() - 1;
+        let lead_inv = divisor.coeffs[d].invert().unwrap();
+        let mut remainder = self.coeffs.clone();
+        let mut quotient = vec![F::ZERO; self.coeffs.len() - d];
+        for i in (0..quotient.len()).rev() {
+            let q = remainder[i + d] * lead_inv;
+            quotient[i] = q;
+            for (r, c) in remainder[i..=i + d].iter_mut().zip(divisor.coeffs.iter()) {
+                *r -= q * c;
+            }
+        }
+        remainder.truncate(d);
+
+        (
+            Self::from_coefficients_vec(quotient),
+            Self::from_coefficients_vec(remainder),
+        )
+    }
+
+    /// Multiplies two polynomials, with FFTs unless they are small or the
+    /// product does not fit in the 2-adic subgroup of `F`, in which case
+    /// Karatsuba's algorithm is used.
+    fn multiply(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+
+        let len = self.coeffs.len() + other.coeffs.len() - 1;
+        let log_n = len.next_power_of_two().trailing_zeros();
+        if self.coeffs.len() * other.coeffs.len() < FFT_MUL_MIN_SIZE || log_n > F::S {
+            let mut coeffs = vec![F::ZERO; len];
+            karatsuba(&mut coeffs, &self.coeffs, &other.coeffs);
+            return Self::from_coefficients_vec(coeffs);
+        }
+
+        let mut omega = F::ROOT_OF_UNITY;
+        let mut omega_inv = F::ROOT_OF_UNITY_INV;
+        for _ in log_n..F::S {
+            omega = omega.square();
+            omega_inv = omega_inv.square();
+        }
+
+        let n = 1 << log_n;
+        let mut a = self.coeffs.clone();
+        let mut b = other.coeffs.clone();
+        a.resize(n, F::ZERO);
+        b.resize(n, F::ZERO);
+        best_fft(&mut a, omega, log_n);
+        best_fft(&mut b, omega, log_n);
+        a.par_iter_mut()
+            .zip(b.par_iter())
+            .for_each(|(a, b)| *a *= b);
+        best_fft(&mut a, omega_inv, log_n);
+
+        let n_inv = F::TWO_INV.pow_vartime([log_n as u64]);
+        a.truncate(len);
+        a.par_iter_mut().for_each(|c| *c *= n_inv);
+        Self::from_coefficients_vec(a)
+    }
+}
+
+/// Smallest operand size split by [`karatsuba`].
+const KARATSUBA_MIN_SIZE: usize = 32;
+
+/// Adds the product of `a` and `b` to `out`, which must have at least
+/// `a.len() + b.len() - 1` coefficients.
+pub(crate) fn karatsuba<F: PrimeField>(out: &mut [F], a: &[F], b: &[F]) {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if b.len() < KARATSUBA_MIN_SIZE {
+        for (i, a) in a.iter().enumerate() {
+            for (o, b) in out[i..].iter_mut().zip(b.iter()) {
+                *o += *a * b;
+            }
+        }
+        return;
+    }
+
+    // unbalanced operands are multiplied by slices of the size of `b`
+    if a.len() > 2 * b.len() {
+        for (i, a) in a.chunks(b.len()).enumerate() {
+            karatsuba(&mut out[i * b.len()..], a, b);
+        }
+        return;
+    }
+
+    // a = a0 + X^m a1, b = b0 + X^m b1
+    let m = (a.len() + 1) / 2;
+    let (a0, a1) = a.split_at(m);
+    let (b0, b1) = b.split_at(m.min(b.len()));
+
+    let mut z0 = vec![F::ZERO; 2 * m - 1];
+    let mut z2 = vec![F::ZERO; a1.len() + b1.len().max(1) - 1];
+    karatsuba(&mut z0, a0, b0);
+    if !b1.is_empty() {
+        karatsuba(&mut z2, a1, b1);
+    }
+
+    // (a0 + a1)(b0 + b1) - z0 - z2 = a0 b1 + a1 b0
+    let mut sa = a0.to_vec();
+    sa.iter_mut().zip(a1.iter()).for_each(|(s, a)| *s += a);
+    let mut sb = b0.to_vec();
+    sb.iter_mut().zip(b1.iter()).for_each(|(s, b)| *s += b);
+    let mut z1 = vec![F::ZERO; sa.len() + sb.len() - 1];
+    karatsuba(&mut z1, &sa, &sb);
+    z1.iter_mut().zip(z0.iter()).for_each(|(z, c)| *z -= c);
+    if !b1.is_empty() {
+        z1.iter_mut().zip(z2.iter()).for_each(|(z, c)| *z -= c);
+    }
+
+    out.iter_mut().zip(z0.iter()).for_each(|(o, c)| *o += c);
+    out[m..]
+        .iter_mut()
+        .zip(z1.iter())
+        .for_each(|(o, c)| *o += c);
+    if !b1.is_empty() {
+        out[2 * m..]
+            .iter_mut()
+            .zip(z2.iter())
+            .for_each(|(o, c)| *o += c);
+    }
+}
+
+impl<F: PrimeField> Neg for DensePolynomial<F> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self {
+        self.coeffs.iter_mut().for_each(|c| *c = -*c);
+        self
+    }
+}
+
+impl<'a, F: PrimeField> Add<&'a DensePolynomial<F>> for &'a DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn add(self, other: &'a DensePolynomial<F>) -> DensePolynomial<F> {
+        let (long, short) = if self.coeffs.len() >= other.coeffs.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut coeffs = long.coeffs.clone();
+        for (a, b) in coeffs.iter_mut().zip(short.coeffs.iter()) {
+            *a += b;
+        }
+        DensePolynomial::from_coefficients_vec(coeffs)
+    }
+}
+
+impl<'a, F: PrimeField> Sub<&'a DensePolynomial<F>> for &'a DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn sub(self, other: &'a DensePolynomial<F>) -> DensePolynomial<F> {
+        let mut coeffs = self.coeffs.clone();
+        if coeffs.len() < other.coeffs.len() {
+            coeffs.resize(other.coeffs.len(), F::ZERO);
+        }
+        for (a, b) in coeffs.iter_mut().zip(other.coeffs.iter()) {
+            *a -= b;
+        }
+        DensePolynomial::from_coefficients_vec(coeffs)
+    }
+}
+
+impl<'a, F: PrimeField> Mul<&'a DensePolynomial<F>> for &'a DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn mul(self, other: &'a DensePolynomial<F>) -> DensePolynomial<F> {
+        self.multiply(other)
+    }
+}
+
+impl<F: PrimeField> Mul<F> for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn mul(self, scalar: F) -> DensePolynomial<F> {
+        DensePolynomial::from_coefficients_vec(self.coeffs.iter().map(|c| *c * scalar).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ff::{Field, PrimeField};
+    use rand_core::OsRng;
+
+    use super::DensePolynomial;
+    use crate::bn256::Fr;
+    use crate::fft::EvaluationDomain;
+
+    fn random_poly(len: usize) -> DensePolynomial<Fr> {
+        DensePolynomial::from_coefficients_vec((0..len).map(|_| Fr::random(OsRng)).collect())
+    }
+
+    #[test]
+    fn test_dense_polynomial() {
+        let zero = DensePolynomial::<Fr>::zero();
+        assert!(zero.is_zero());
+        assert_eq!(
+            DensePolynomial::from_coefficients_slice(&[Fr::ONE, Fr::ZERO, Fr::ZERO]).degree(),
+            0
+        );
+
+        for (la, lb) in [(0, 3), (1, 1), (5, 8), (40, 30), (300, 200)] {
+            let a = random_poly(la);
+            let b = random_poly(lb);
+            let x = Fr::random(OsRng);
+
+            let (ea, eb) = (a.evaluate(x), b.evaluate(x));
+            assert_eq!((&a + &b).evaluate(x), ea + eb);
+            assert_eq!((&a - &b).evaluate(x), ea - eb);
+            assert_eq!((&a * &b).evaluate(x), ea * eb);
+            assert_eq!((&a * Fr::from(3)).evaluate(x), ea * Fr::from(3));
+            assert_eq!((-a.clone()).evaluate(x), -ea);
+            assert!((&a - &a).is_zero());
+
+            let points: Vec<_> = (0..10).map(|_| Fr::random(OsRng)).collect();
+            let evals = a.evaluate_batch(&points);
+            for (x, e) in points.iter().zip(evals.iter()) {
+                assert_eq!(a.evaluate(*x), *e);
+            }
+
+            if !b.is_zero() {
+                let (q, r) = a.div_rem(&b);
+                assert!(r.is_zero() || r.degree() < b.degree());
+                assert_eq!(&(&q * &b) + &r, a);
+            }
+        }
+
+        // parallel Horner's rule
+        let a = random_poly(1 << 12);
+        let x = Fr::random(OsRng);
+        let expected = a.coeffs().iter().rev().fold(Fr::ZERO, |acc, c| acc * x + c);
+        assert_eq!(a.evaluate(x), expected);
+    }
+
+    fn run_multiply<F: PrimeField>() {
+        for (la, lb) in [(32, 32), (33, 100), (1, 600), (300, 200)] {
+            let a = DensePolynomial::<F>::from_coefficients_vec(
+                (0..la).map(|_| F::random(OsRng)).collect(),
+            );
+            let b = DensePolynomial::<F>::from_coefficients_vec(
+                (0..lb).map(|_| F::random(OsRng)).collect(),
+            );
+            let x = F::random(OsRng);
+            assert_eq!((&a * &b).evaluate(x), a.evaluate(x) * b.evaluate(x));
+        }
+    }
+
+    #[test]
+    fn test_multiply_low_two_adicity() {
+        run_multiply::<crate::secp256k1::Fp>();
+        run_multiply::<crate::secp256k1::Fq>();
+        run_multiply::<crate::small_fields::Mersenne31>();
+        run_multiply::<Fr>();
+    }
+
+    #[test]
+    fn test_divide_by_vanishing_poly() {
+        let domain = EvaluationDomain::<Fr>::new(4);
+        let z = domain.evaluate_vanishing_polynomial(Fr::ZERO);
+        assert_eq!(z, -Fr::ONE);
+
+        for len in [0, 5, 16, 17, 100] {
+            let a = random_poly(len);
+            let (q, r) = a.divide_by_vanishing_poly(&domain);
+            assert!(r.coeffs().len() <= domain.size());
+
+            let x = Fr::random(OsRng);
+            assert_eq!(
+                a.evaluate(x),
+                q.evaluate(x) * domain.evaluate_vanishing_polynomial(x) + r.evaluate(x)
+            );
+        }
+    }
+}
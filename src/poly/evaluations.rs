@@ -0,0 +1,158 @@
+use std::ops::{Add, Mul, Sub};
+
+use ff::PrimeField;
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
+
+use super::DensePolynomial;
+use crate::fft::EvaluationDomain;
+
+/// A polynomial of degree less than $n$ given by its evaluations on an
+/// [`EvaluationDomain`] of size $n$, with `evals[i]` the evaluation at
+/// $\omega^i$.
+#[derive(Clone, Debug)]
+pub struct Evaluations<'a, F: PrimeField> {
+    evals: Vec<F>,
+    domain: &'a EvaluationDomain<F>,
+}
+
+impl<'a, F: PrimeField> Evaluations<'a, F> {
+    /// Returns the polynomial with evaluations `evals` on `domain`.
+    ///
+    /// This function will panic if `evals` is not of the size of `domain`.
+    pub fn from_vec_and_domain(evals: Vec<F>, domain: &'a EvaluationDomain<F>) -> Self {
+        assert_eq!(evals.len(), domain.size(), "evaluations of the wrong size");
+        Self { evals, domain }
+    }
+
+    /// Returns the evaluations.
+    pub fn evals(&self) -> &[F] {
+        &self.evals
+    }
+
+    /// Returns the evaluations.
+    pub fn into_evals(self) -> Vec<F> {
+        self.evals
+    }
+
+    /// Returns the domain of the evaluations.
+    pub fn domain(&self) -> &'a EvaluationDomain<F> {
+        self.domain
+    }
+
+    /// Returns the polynomial in coefficient form.
+    pub fn interpolate(self) -> DensePolynomial<F> {
+        let mut coeffs = self.evals;
+        self.domain.ifft(&mut coeffs);
+        DensePolynomial::from_coefficients_vec(coeffs)
+    }
+
+    /// Evaluates the polynomial at `x` in the Lagrange basis of the domain,
+    /// without interpolating it.
+    ///
+    /// This will use multithreading if beneficial.
+    pub fn evaluate(&self, x: F) -> F {
+        self.domain
+            .evaluate_lagrange_basis(x)
+            .par_iter()
+            .zip(self.evals.par_iter())
+            .map(|(l, e)| *l * e)
+            .reduce(|| F::ZERO, |a, b| a + b)
+    }
+
+    fn zip_with(&self, other: &Self, f: impl Fn(&F, &F) -> F + Sync) -> Self {
+        assert!(
+            self.domain.size() == other.domain.size()
+                && self.domain.omega() == other.domain.omega()
+                && self.domain.coset_shift() == other.domain.coset_shift(),
+            "evaluations over different domains"
+        );
+        let evals = self
+            .evals
+            .par_iter()
+            .zip(other.evals.par_iter())
+            .map(|(a, b)| f(a, b))
+            .collect();
+        Self {
+            evals,
+            domain: self.domain,
+        }
+    }
+}
+
+impl<'a, F: PrimeField> Add<&Evaluations<'a, F>> for &Evaluations<'a, F> {
+    type Output = Evaluations<'a, F>;
+
+    fn add(self, other: &Evaluations<'a, F>) -> Evaluations<'a, F> {
+        self.zip_with(other, |a, b| *a + b)
+    }
+}
+
+impl<'a, F: PrimeField> Sub<&Evaluations<'a, F>> for &Evaluations<'a, F> {
+    type Output = Evaluations<'a, F>;
+
+    fn sub(self, other: &Evaluations<'a, F>) -> Evaluations<'a, F> {
+        self.zip_with(other, |a, b| *a - b)
+    }
+}
+
+/// Pointwise product. The product is the evaluations of the product of the
+/// polynomials only if its degree is less than the size of the domain.
+impl<'a, F: PrimeField> Mul<&Evaluations<'a, F>> for &Evaluations<'a, F> {
+    type Output = Evaluations<'a, F>;
+
+    fn mul(self, other: &Evaluations<'a, F>) -> Evaluations<'a, F> {
+        self.zip_with(other, |a, b| *a * b)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ff::Field;
+    use rand_core::OsRng;
+
+    use super::Evaluations;
+    use crate::bn256::Fr;
+    use crate::fft::EvaluationDomain;
+    use crate::poly::DensePolynomial;
+
+    #[test]
+    fn test_evaluations() {
+        let domain = EvaluationDomain::<Fr>::new(5);
+        let random_poly = |len| {
+            DensePolynomial::from_coefficients_vec((0..len).map(|_| Fr::random(OsRng)).collect())
+        };
+        let a = random_poly(12);
+        let b = random_poly(16);
+        let ea = a.clone().evaluate_over_domain(&domain);
+        let eb = b.clone().evaluate_over_domain(&domain);
+
+        for (i, e) in ea.evals().iter().enumerate() {
+            assert_eq!(*e, a.evaluate(domain.element(i)));
+        }
+        assert_eq!(ea.clone().interpolate(), a);
+
+        let x = Fr::random(OsRng);
+        assert_eq!(ea.evaluate(x), a.evaluate(x));
+        assert_eq!(ea.evaluate(domain.element(3)), ea.evals()[3]);
+
+        assert_eq!((&ea + &eb).interpolate(), &a + &b);
+        assert_eq!((&ea - &eb).interpolate(), &a - &b);
+        assert_eq!((&ea * &eb).interpolate(), &a * &b);
+
+        let evals = Evaluations::from_vec_and_domain(vec![Fr::ONE; 32], &domain);
+        assert_eq!(
+            evals.interpolate(),
+            DensePolynomial::from_coefficients_slice(&[Fr::ONE])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "evaluations over different domains")]
+    fn test_evaluations_different_domains() {
+        let domain = EvaluationDomain::<Fr>::new(3);
+        let coset = EvaluationDomain::<Fr>::new(3).with_coset_shift(Fr::from(3));
+        let a = Evaluations::from_vec_and_domain(vec![Fr::ONE; 8], &domain);
+        let b = Evaluations::from_vec_and_domain(vec![Fr::ONE; 8], &coset);
+        let _ = &a + &b;
+    }
+}
@@ -0,0 +1,10 @@
+//! Univariate polynomials over prime fields, in coefficient form with
+//! [`DensePolynomial`] and in evaluation form over an
+//! [`EvaluationDomain`](crate::fft::EvaluationDomain) with [`Evaluations`].
+
+mod dense;
+mod evaluations;
+
+pub(crate) use dense::karatsuba;
+pub use dense::DensePolynomial;
+pub use evaluations::Evaluations;
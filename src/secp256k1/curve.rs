@@ -1,3 +1,5 @@
+use crate::arithmetic::{mul_512, sbb, CurveEndo, EndoParameters};
+use crate::endo;
 use crate::ff::WithSmallOrderMulGroup;
 use crate::ff::{Field, PrimeField};
 use crate::group::{prime::PrimeCurveAffine, Curve, Group as _, GroupEncoding};
@@ -59,41 +61,61 @@ new_curve_impl!(
     SECP_A,
     SECP_B,
     "secp256k1",
-    |domain_prefix| hash_to_curve(domain_prefix, hash_to_curve_suite(b"secp256k1_XMD:SHA-256_SSWU_RO_")),
+    |domain_prefix| hash_to_curve(domain_prefix, crate::hash_to_curve::Suite::secp256k1_sswu(b"secp256k1_XMD:SHA-256_SSWU_RO_")),
     crate::serde::CompressedFlagConfig::Extra,
     standard_sign
 );
 
-fn hash_to_curve_suite(domain: &[u8]) -> crate::hash_to_curve::Suite<Secp256k1, sha2::Sha256, 48> {
-    // Z = -11 (reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-suites-for-secp256k1>)
-    // 0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc24
-    const SSWU_Z: Fp = Fp::from_raw([
-        0xfffffffefffffc24,
-        0xffffffffffffffff,
-        0xffffffffffffffff,
-        0xffffffffffffffff,
-    ]);
+// The lattice basis of the GLV decomposition, from
+// <https://github.com/bitcoin-core/secp256k1/blob/master/src/scalar_impl.h>.
+const ENDO_PARAMS_SECP256K1: EndoParameters = EndoParameters {
+    // round(2^256 * -b1 / n)
+    gamma1: [0x6f547fa90abfe4c4, 0xe4437ed6010e8828, 0, 0],
+    // round(2^256 * b2 / n)
+    gamma2: [0xe86c90e49284eb15, 0x3086d221a7d46bcd, 0, 0],
+    b1: [0x6f547fa90abfe4c3, 0xe4437ed6010e8828, 0, 0],
+    b2: [0xe86c90e49284eb15, 0x3086d221a7d46bcd, 0, 0],
+};
+
+endo!(Secp256k1, Fq, ENDO_PARAMS_SECP256K1);
+
+crate::impl_curve_msm!(Secp256k1Affine, "glv");
 
-    // E': y'^2 = x'^3 + A' * x' + B', where
-    // A': 0x3f8731abdd661adca08a5558f0f5d272e953d363cb6f0e5d405447c01a444533
-    // B': 1771
-    // (reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-suites-for-secp256k1>)
-    pub const ISO_SECP_A: Fp = Fp::from_raw([
-        0x405447c01a444533,
-        0xe953d363cb6f0e5d,
-        0xa08a5558f0f5d272,
-        0x3f8731abdd661adc,
-    ]);
+impl<D: crate::hash_to_curve::ExpandMessage> crate::hash_to_curve::Suite<Secp256k1, D, 48> {
+    /// The simplified SWU suite of secp256k1, under the domain separation tag
+    /// `domain`, e.g. `secp256k1_XMD:SHA-256_SSWU_RO_` with `D = Sha256`.
+    pub fn secp256k1_sswu(domain: &[u8]) -> Self {
+        // Z = -11 (reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-suites-for-secp256k1>)
+        // 0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc24
+        const SSWU_Z: Fp = Fp::from_raw([
+            0xfffffffefffffc24,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+        ]);
 
-    pub const ISO_SECP_B: Fp = Fp::from_raw([1771, 0, 0, 0]);
+        // E': y'^2 = x'^3 + A' * x' + B', where
+        // A': 0x3f8731abdd661adca08a5558f0f5d272e953d363cb6f0e5d405447c01a444533
+        // B': 1771
+        // (reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-suites-for-secp256k1>)
+        pub const ISO_SECP_A: Fp = Fp::from_raw([
+            0x405447c01a444533,
+            0xe953d363cb6f0e5d,
+            0xa08a5558f0f5d272,
+            0x3f8731abdd661adc,
+        ]);
 
-    let iso_map = crate::hash_to_curve::Iso {
-        a: ISO_SECP_A,
-        b: ISO_SECP_B,
-        map: Box::new(iso_map),
-    };
+        pub const ISO_SECP_B: Fp = Fp::from_raw([1771, 0, 0, 0]);
 
-    crate::hash_to_curve::Suite::new(domain, SSWU_Z, crate::hash_to_curve::Method::SSWU(iso_map))
+        let iso_map = crate::hash_to_curve::Iso {
+            a: ISO_SECP_A,
+            b: ISO_SECP_B,
+            map: Box::new(iso_map),
+        };
+
+        Self::new(domain, SSWU_Z, crate::hash_to_curve::Method::SSWU(iso_map))
+            .expect("secp256k1 SSWU parameters are valid")
+    }
 }
 
 #[allow(clippy::type_complexity)]
@@ -104,6 +126,18 @@ pub(crate) fn hash_to_curve<'a>(
     Box::new(move |message| suite.hash_to_curve(domain_prefix, message))
 }
 
+impl Secp256k1 {
+    /// Returns the `secp256k1_XMD:SHA-256_SSWU_NU_` encoding to the curve, the cheaper but
+    /// non-uniform counterpart of [`CurveExt::hash_to_curve`].
+    #[allow(clippy::type_complexity)]
+    pub fn encode_to_curve<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> Self + 'a> {
+        let suite = crate::hash_to_curve::Suite::<Self, sha2::Sha256, 48>::secp256k1_sswu(
+            b"secp256k1_XMD:SHA-256_SSWU_NU_",
+        );
+        Box::new(move |message| suite.encode_to_curve(domain_prefix, message))
+    }
+}
+
 /// 3-Isogeny Map for Secp256k1
 /// Reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-3-isogeny-map-for-secp256k1>
 pub(crate) fn iso_map(x: Fp, y: Fp, z: Fp) -> Secp256k1 {
@@ -227,6 +261,7 @@ mod test {
     use group::UncompressedEncoding;
     crate::curve_testing_suite!(Secp256k1);
     crate::curve_testing_suite!(Secp256k1, "endo_consistency");
+    crate::curve_testing_suite!(Secp256k1, "endo");
     crate::curve_testing_suite!(Secp256k1, "ecdsa_example");
     crate::curve_testing_suite!(
         Secp256k1,
@@ -283,4 +318,50 @@ mod test {
             test.run("QUUX-V01-CS02-with-");
         });
     }
+
+    #[test]
+    fn test_encode_to_curve() {
+        // Test vectors are taken from
+        // https://www.rfc-editor.org/rfc/rfc9380.html#name-secp256k1_xmdsha-256_sswu_nu_
+        let encoder = Secp256k1::encode_to_curve("QUUX-V01-CS02-with-");
+        [
+            TestH2C::<Secp256k1Affine>::new(
+                b"",
+                crate::tests::point_from_hex(
+                    "a4792346075feae77ac3b30026f99c1441b4ecf666ded19b7522cf65c4c55c5b",
+                    "62c59e2a6aeed1b23be5883e833912b08ba06be7f57c0e9cdc663f31639ff3a7",
+                ),
+            ),
+            TestH2C::<Secp256k1Affine>::new(
+                b"abc",
+                crate::tests::point_from_hex(
+                    "3f3b5842033fff837d504bb4ce2a372bfeadbdbd84a1d2b678b6e1d7ee426b9d",
+                    "902910d1fef15d8ae2006fc84f2a5a7bda0e0407dc913062c3a493c4f5d876a5",
+                ),
+            ),
+            TestH2C::<Secp256k1Affine>::new(
+                b"abcdef0123456789",
+                crate::tests::point_from_hex(
+                    "07644fa6281c694709f53bdd21bed94dab995671e4a8cd1904ec4aa50c59bfdf",
+                    "c79f8d1dad79b6540426922f7fbc9579c3018dafeffcd4552b1626b506c21e7b",
+                ),
+            ),
+            TestH2C::<Secp256k1Affine>::new(
+                b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+                crate::tests::point_from_hex(
+                    "b734f05e9b9709ab631d960fa26d669c4aeaea64ae62004b9d34f483aa9acc33",
+                    "03fc8a4a5a78632e2eb4d8460d69ff33c1d72574b79a35e402e801f2d0b1d6ee",
+                ),
+            ),
+            TestH2C::<Secp256k1Affine>::new(
+                b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                crate::tests::point_from_hex(
+                    "17d22b867658977b5002dbe8d0ee70a8cfddec3eec50fb93f36136070fd9fa6c",
+                    "e9178ff02f4dab73480f8dd590328aea99856a7b6cc8e5a6cdf289ecc2a51718",
+                ),
+            ),
+        ]
+        .iter()
+        .for_each(|test| test.run_with(&encoder));
+    }
 }
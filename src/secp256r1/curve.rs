@@ -70,29 +70,36 @@ new_curve_impl!(
     SECP_A,
     SECP_B,
     "secp256r1",
-    |domain_prefix| hash_to_curve(domain_prefix, hash_to_curve_suite(b"P256_XMD:SHA-256_SSWU_RO_")),
+    |domain_prefix| hash_to_curve(domain_prefix, crate::hash_to_curve::Suite::p256_sswu(b"P256_XMD:SHA-256_SSWU_RO_")),
     crate::serde::CompressedFlagConfig::Extra,
     standard_sign
 );
 
-fn hash_to_curve_suite(domain: &[u8]) -> crate::hash_to_curve::Suite<Secp256r1, sha2::Sha256, 48> {
-    // Optimal Z with: <https://datatracker.ietf.org/doc/html/rfc9380#sswu-z-code>
-    // 0xffffffff00000001000000000000000000000000fffffffffffffffffffffff5
-    // Z = -10 (reference: <https://www.rfc-editor.org/rfc/rfc9380.html#section-8.2>)
-    const SSWU_Z: Fp = Fp::from_raw([
-        0xfffffffffffffff5,
-        0x00000000ffffffff,
-        0x0000000000000000,
-        0xffffffff00000001,
-    ]);
-
-    let iso_map = crate::hash_to_curve::Iso {
-        a: Secp256r1::a(),
-        b: Secp256r1::b(),
-        map: Box::new(move |x, y, z| Secp256r1 { x, y, z }),
-    };
-
-    crate::hash_to_curve::Suite::new(domain, SSWU_Z, crate::hash_to_curve::Method::SSWU(iso_map))
+crate::impl_curve_msm!(Secp256r1Affine);
+
+impl<D: crate::hash_to_curve::ExpandMessage> crate::hash_to_curve::Suite<Secp256r1, D, 48> {
+    /// The simplified SWU suite of P-256, under the domain separation tag
+    /// `domain`, e.g. `P256_XMD:SHA-256_SSWU_RO_` with `D = Sha256`.
+    pub fn p256_sswu(domain: &[u8]) -> Self {
+        // Optimal Z with: <https://datatracker.ietf.org/doc/html/rfc9380#sswu-z-code>
+        // 0xffffffff00000001000000000000000000000000fffffffffffffffffffffff5
+        // Z = -10 (reference: <https://www.rfc-editor.org/rfc/rfc9380.html#section-8.2>)
+        const SSWU_Z: Fp = Fp::from_raw([
+            0xfffffffffffffff5,
+            0x00000000ffffffff,
+            0x0000000000000000,
+            0xffffffff00000001,
+        ]);
+
+        let iso_map = crate::hash_to_curve::Iso {
+            a: Secp256r1::a(),
+            b: Secp256r1::b(),
+            map: Box::new(move |x, y, z| Secp256r1 { x, y, z }),
+        };
+
+        Self::new(domain, SSWU_Z, crate::hash_to_curve::Method::SSWU(iso_map))
+            .expect("P-256 SSWU parameters are valid")
+    }
 }
 
 #[allow(clippy::type_complexity)]
@@ -103,6 +110,18 @@ pub(crate) fn hash_to_curve<'a>(
     Box::new(move |message| suite.hash_to_curve(domain_prefix, message))
 }
 
+impl Secp256r1 {
+    /// Returns the `P256_XMD:SHA-256_SSWU_NU_` encoding to the curve, the cheaper but
+    /// non-uniform counterpart of [`CurveExt::hash_to_curve`].
+    #[allow(clippy::type_complexity)]
+    pub fn encode_to_curve<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> Self + 'a> {
+        let suite = crate::hash_to_curve::Suite::<Self, sha2::Sha256, 48>::p256_sswu(
+            b"P256_XMD:SHA-256_SSWU_NU_",
+        );
+        Box::new(move |message| suite.encode_to_curve(domain_prefix, message))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::tests::curve::TestH2C;
@@ -166,4 +185,50 @@ mod test {
             test.run("QUUX-V01-CS02-with-");
         });
     }
+
+    #[test]
+    fn test_encode_to_curve() {
+        // Test vectors are taken from
+        // https://www.rfc-editor.org/rfc/rfc9380.html#name-p256_xmdsha-256_sswu_nu_
+        let encoder = Secp256r1::encode_to_curve("QUUX-V01-CS02-with-");
+        [
+            TestH2C::<Secp256r1Affine>::new(
+                b"",
+                crate::tests::point_from_hex(
+                    "f871caad25ea3b59c16cf87c1894902f7e7b2c822c3d3f73596c5ace8ddd14d1",
+                    "87b9ae23335bee057b99bac1e68588b18b5691af476234b8971bc4f011ddc99b",
+                ),
+            ),
+            TestH2C::<Secp256r1Affine>::new(
+                b"abc",
+                crate::tests::point_from_hex(
+                    "fc3f5d734e8dce41ddac49f47dd2b8a57257522a865c124ed02b92b5237befa4",
+                    "fe4d197ecf5a62645b9690599e1d80e82c500b22ac705a0b421fac7b47157866",
+                ),
+            ),
+            TestH2C::<Secp256r1Affine>::new(
+                b"abcdef0123456789",
+                crate::tests::point_from_hex(
+                    "f164c6674a02207e414c257ce759d35eddc7f55be6d7f415e2cc177e5d8faa84",
+                    "3aa274881d30db70485368c0467e97da0e73c18c1d00f34775d012b6fcee7f97",
+                ),
+            ),
+            TestH2C::<Secp256r1Affine>::new(
+                b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+                crate::tests::point_from_hex(
+                    "324532006312be4f162614076460315f7a54a6f85544da773dc659aca0311853",
+                    "8d8197374bcd52de2acfefc8a54fe2c8d8bebd2a39f16be9b710e4b1af6ef883",
+                ),
+            ),
+            TestH2C::<Secp256r1Affine>::new(
+                b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                crate::tests::point_from_hex(
+                    "5c4bad52f81f39c8e8de1260e9a06d72b8b00a0829a8ea004a610b0691bea5d9",
+                    "c801e7c0782af1f74f24fc385a8555da0582032a3ce038de637ccdcb16f7ef7b",
+                ),
+            ),
+        ]
+        .iter()
+        .for_each(|test| test.run_with(&encoder));
+    }
 }
@@ -14,6 +14,7 @@ impl_field!(
 );
 
 crate::extend_field_legendre!(Fp);
+crate::impl_extension_degree!(Fp, 1);
 crate::impl_binops_calls!(Fp);
 crate::impl_binops_additive!(Fp, Fp);
 crate::impl_binops_multiplicative!(Fp, Fp);
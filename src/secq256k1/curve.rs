@@ -47,6 +47,8 @@ new_curve_impl!(
     standard_sign
 );
 
+crate::impl_curve_msm!(Secq256k1Affine);
+
 impl group::cofactor::CofactorGroup for Secq256k1 {
     type Subgroup = Secq256k1;
 
@@ -67,11 +69,20 @@ impl Secq256k1 {
     const SVDW_Z: Fq = Fq::ONE;
 
     fn default_hash_to_curve_suite() -> crate::hash_to_curve::Suite<Self, sha2::Sha256, 48> {
-        crate::hash_to_curve::Suite::<Self, sha2::Sha256, 48>::new(
-            b"secq256k1_XMD:SHA-256_SVDW_RO_",
-            Self::SVDW_Z,
+        crate::hash_to_curve::Suite::secq256k1_svdw(b"secq256k1_XMD:SHA-256_SVDW_RO_")
+    }
+}
+
+impl<D: crate::hash_to_curve::ExpandMessage> crate::hash_to_curve::Suite<Secq256k1, D, 48> {
+    /// The Shallue-van de Woestijne suite of secq256k1, under the domain separation
+    /// tag `domain`, e.g. `secq256k1_XMD:SHA-256_SVDW_RO_` with `D = Sha256`.
+    pub fn secq256k1_svdw(domain: &[u8]) -> Self {
+        Self::new(
+            domain,
+            Secq256k1::SVDW_Z,
             crate::hash_to_curve::Method::SVDW,
         )
+        .expect("secq256k1 SVDW parameters are valid")
     }
 }
 
@@ -0,0 +1,143 @@
+use subtle::CtOption;
+
+/// An element of the BabyBear field, of modulus $p = 15 \cdot 2^{27} + 1$,
+/// stored in Montgomery form with $R = 2^{32}$.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BabyBear(pub(crate) u32);
+
+/// $R^2 \bmod p$.
+const R2: u32 = 0x45dd_dde3;
+
+/// $p^{-1} \bmod 2^{32}$.
+const INV: u32 = 0x8800_0001;
+
+impl BabyBear {
+    pub const SIZE: usize = 4;
+    pub(crate) const MODULUS_INNER: u32 = 0x7800_0001;
+
+    /// Returns zero, the additive identity.
+    #[inline(always)]
+    pub const fn zero() -> Self {
+        Self(0)
+    }
+
+    /// Returns one, the multiplicative identity.
+    #[inline(always)]
+    pub const fn one() -> Self {
+        // R mod p
+        Self(0x0fff_fffe)
+    }
+
+    /// Converts an integer smaller than the modulus into a field element.
+    #[inline(always)]
+    pub const fn from_canonical(val: u32) -> Self {
+        debug_assert!(val < Self::MODULUS_INNER);
+        Self::montgomery_reduce(val as u64 * R2 as u64)
+    }
+
+    /// Returns the canonical representative of the element, in $[0, p)$.
+    #[inline(always)]
+    pub const fn to_canonical(&self) -> u32 {
+        Self::montgomery_reduce(self.0 as u64).0
+    }
+
+    /// Computes $x R^{-1} \bmod p$ for $x < p 2^{32}$.
+    #[inline(always)]
+    const fn montgomery_reduce(x: u64) -> Self {
+        // u = x mod 2^32, so that x - u p is divisible by 2^32
+        let m = (x as u32).wrapping_mul(INV);
+        let u = m as u64 * Self::MODULUS_INNER as u64;
+        let (x_hi, u_hi) = ((x >> 32) as u32, (u >> 32) as u32);
+        let (res, borrow) = x_hi.overflowing_sub(u_hi);
+        Self(if borrow {
+            res.wrapping_add(Self::MODULUS_INNER)
+        } else {
+            res
+        })
+    }
+
+    #[inline]
+    pub const fn add(&self, rhs: &Self) -> Self {
+        // p < 2^31, so the sum does not overflow
+        let sum = self.0 + rhs.0;
+        Self(if sum >= Self::MODULUS_INNER {
+            sum - Self::MODULUS_INNER
+        } else {
+            sum
+        })
+    }
+
+    #[inline]
+    pub const fn sub(&self, rhs: &Self) -> Self {
+        let (diff, borrow) = self.0.overflowing_sub(rhs.0);
+        Self(if borrow {
+            diff.wrapping_add(Self::MODULUS_INNER)
+        } else {
+            diff
+        })
+    }
+
+    #[inline]
+    pub const fn neg(&self) -> Self {
+        Self::zero().sub(self)
+    }
+
+    #[inline]
+    pub const fn mul(&self, rhs: &Self) -> Self {
+        Self::montgomery_reduce(self.0 as u64 * rhs.0 as u64)
+    }
+
+    #[inline]
+    pub const fn double(&self) -> Self {
+        self.add(self)
+    }
+
+    #[inline]
+    pub const fn square(&self) -> Self {
+        self.mul(self)
+    }
+
+    /// Computes the square root of this element, if it exists, with the
+    /// Tonelli-Shanks algorithm.
+    pub fn sqrt(&self) -> CtOption<Self> {
+        // (t - 1) / 2, where p - 1 = 2^27 t
+        ff::helpers::sqrt_tonelli_shanks(self, [7])
+    }
+}
+
+crate::impl_small_field!(
+    BabyBear,
+    u32,
+    modulus = "0x78000001",
+    mul_gen = 31,
+    root_of_unity = 0x1a42_7a41,
+    root_of_unity_inv = 0x6627_31d4,
+    delta = 0x76f0_7a0c,
+    zeta = 0x4e5d_1533,
+    from_uniform = [20, 64]
+);
+
+#[cfg(test)]
+mod test {
+    use super::BabyBear;
+    use crate::{
+        arith_test, constants_test, from_uniform_bytes_test, legendre_test, serde_test, test,
+    };
+
+    constants_test!(BabyBear);
+    arith_test!(BabyBear);
+    legendre_test!(BabyBear);
+    test!(arith, BabyBear, sqrt_test, 1000);
+    serde_test!(BabyBear PrimeFieldBits);
+    from_uniform_bytes_test!(BabyBear, 1000, L 20, L 64);
+
+    #[test]
+    fn test_montgomery_form() {
+        assert_eq!(BabyBear::one().to_canonical(), 1);
+        assert_eq!(BabyBear::from(0x7800_0001 + 5).to_canonical(), 5);
+        let minus_one = -BabyBear::one();
+        assert_eq!(minus_one.to_canonical(), BabyBear::MODULUS_INNER - 1);
+        assert_eq!(minus_one * minus_one, BabyBear::one());
+        assert_eq!(BabyBear::from(1 << 27) * BabyBear::from(15), minus_one);
+    }
+}
@@ -0,0 +1,148 @@
+use subtle::CtOption;
+
+/// An element of the Goldilocks field, of modulus $p = 2^{64} - 2^{32} + 1$,
+/// stored as its canonical representative.
+///
+/// Reductions use $2^{64} \equiv 2^{32} - 1$ and $2^{96} \equiv -1 \pmod p$.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Goldilocks(pub(crate) u64);
+
+/// $2^{64} - p = 2^{32} - 1$.
+const EPSILON: u64 = 0xffff_ffff;
+
+impl Goldilocks {
+    pub const SIZE: usize = 8;
+    pub(crate) const MODULUS_INNER: u64 = 0xffff_ffff_0000_0001;
+
+    /// Returns zero, the additive identity.
+    #[inline(always)]
+    pub const fn zero() -> Self {
+        Self(0)
+    }
+
+    /// Returns one, the multiplicative identity.
+    #[inline(always)]
+    pub const fn one() -> Self {
+        Self(1)
+    }
+
+    /// Converts an integer smaller than the modulus into a field element.
+    #[inline(always)]
+    pub const fn from_canonical(val: u64) -> Self {
+        debug_assert!(val < Self::MODULUS_INNER);
+        Self(val)
+    }
+
+    /// Returns the canonical representative of the element, in $[0, p)$.
+    #[inline(always)]
+    pub const fn to_canonical(&self) -> u64 {
+        self.0
+    }
+
+    /// Reduces an integer smaller than $2^{128}$.
+    #[inline(always)]
+    const fn reduce128(x: u128) -> Self {
+        let lo = x as u64;
+        let hi = (x >> 64) as u64;
+        let (hi_hi, hi_lo) = (hi >> 32, hi & EPSILON);
+
+        // lo - hi_hi, where a borrow of 2^64 is corrected by EPSILON
+        let (t0, borrow) = lo.overflowing_sub(hi_hi);
+        let t0 = if borrow { t0.wrapping_sub(EPSILON) } else { t0 };
+        // hi_lo * 2^64 = hi_lo * EPSILON, which fits in 64 bits
+        let t1 = hi_lo * EPSILON;
+        let (t2, carry) = t0.overflowing_add(t1);
+        let t2 = if carry { t2 + EPSILON } else { t2 };
+        Self::reduce64(t2)
+    }
+
+    /// Reduces an integer smaller than $2^{64} < 2 p$.
+    #[inline(always)]
+    const fn reduce64(x: u64) -> Self {
+        if x >= Self::MODULUS_INNER {
+            Self(x - Self::MODULUS_INNER)
+        } else {
+            Self(x)
+        }
+    }
+
+    #[inline]
+    pub const fn add(&self, rhs: &Self) -> Self {
+        let (sum, carry) = self.0.overflowing_add(rhs.0);
+        let sum = if carry { sum + EPSILON } else { sum };
+        Self::reduce64(sum)
+    }
+
+    #[inline]
+    pub const fn sub(&self, rhs: &Self) -> Self {
+        let (diff, borrow) = self.0.overflowing_sub(rhs.0);
+        Self(if borrow { diff - EPSILON } else { diff })
+    }
+
+    #[inline]
+    pub const fn neg(&self) -> Self {
+        Self::zero().sub(self)
+    }
+
+    #[inline]
+    pub const fn mul(&self, rhs: &Self) -> Self {
+        Self::reduce128(self.0 as u128 * rhs.0 as u128)
+    }
+
+    #[inline]
+    pub const fn double(&self) -> Self {
+        self.add(self)
+    }
+
+    #[inline]
+    pub const fn square(&self) -> Self {
+        self.mul(self)
+    }
+
+    /// Computes the square root of this element, if it exists, with the
+    /// Tonelli-Shanks algorithm.
+    pub fn sqrt(&self) -> CtOption<Self> {
+        // (t - 1) / 2, where p - 1 = 2^32 t
+        ff::helpers::sqrt_tonelli_shanks(self, [0x7fff_ffff])
+    }
+}
+
+crate::impl_small_field!(
+    Goldilocks,
+    u64,
+    modulus = "0xffffffff00000001",
+    mul_gen = 7,
+    root_of_unity = 0x1856_29dc_da58_878c,
+    root_of_unity_inv = 0x76b6_b635_b6fc_8719,
+    delta = 0xaa5b_2509_f86b_b4d4,
+    zeta = 0xffff_fffe_0000_0001,
+    from_uniform = [24, 64]
+);
+
+#[cfg(test)]
+mod test {
+    use super::Goldilocks;
+    use crate::{
+        arith_test, constants_test, from_uniform_bytes_test, legendre_test, serde_test, test,
+    };
+
+    constants_test!(Goldilocks);
+    arith_test!(Goldilocks);
+    legendre_test!(Goldilocks);
+    test!(arith, Goldilocks, sqrt_test, 1000);
+    serde_test!(Goldilocks PrimeFieldBits);
+    from_uniform_bytes_test!(Goldilocks, 1000, L 24, L 64);
+
+    #[test]
+    fn test_reduction_edge_cases() {
+        let minus_one = -Goldilocks::one();
+        assert_eq!(minus_one.to_canonical(), Goldilocks::MODULUS_INNER - 1);
+        assert_eq!(minus_one * minus_one, Goldilocks::one());
+        assert_eq!(minus_one + minus_one, -Goldilocks::from(2));
+        assert_eq!(Goldilocks::from(u64::MAX), Goldilocks::from(0xffff_fffe));
+        // 2^96 = -1
+        let two_32 = Goldilocks::from(1 << 32);
+        assert_eq!(two_32.square() * two_32, minus_one);
+        assert_eq!(Goldilocks::zero() - Goldilocks::one(), minus_one);
+    }
+}
@@ -0,0 +1,133 @@
+use subtle::{ConstantTimeEq, CtOption};
+
+/// An element of the Mersenne-31 field, of modulus $p = 2^{31} - 1$, stored as
+/// its canonical representative.
+///
+/// Reductions fold the bits above the 31st onto the lower ones, since
+/// $2^{31} \equiv 1 \pmod p$.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Mersenne31(pub(crate) u32);
+
+impl Mersenne31 {
+    pub const SIZE: usize = 4;
+    pub(crate) const MODULUS_INNER: u32 = 0x7fff_ffff;
+
+    /// Returns zero, the additive identity.
+    #[inline(always)]
+    pub const fn zero() -> Self {
+        Self(0)
+    }
+
+    /// Returns one, the multiplicative identity.
+    #[inline(always)]
+    pub const fn one() -> Self {
+        Self(1)
+    }
+
+    /// Converts an integer smaller than the modulus into a field element.
+    #[inline(always)]
+    pub const fn from_canonical(val: u32) -> Self {
+        debug_assert!(val < Self::MODULUS_INNER);
+        Self(val)
+    }
+
+    /// Returns the canonical representative of the element, in $[0, p)$.
+    #[inline(always)]
+    pub const fn to_canonical(&self) -> u32 {
+        self.0
+    }
+
+    /// Reduces an integer smaller than $2 p$.
+    #[inline(always)]
+    const fn reduce32(x: u32) -> Self {
+        Self(if x >= Self::MODULUS_INNER {
+            x - Self::MODULUS_INNER
+        } else {
+            x
+        })
+    }
+
+    #[inline]
+    pub const fn add(&self, rhs: &Self) -> Self {
+        Self::reduce32(self.0 + rhs.0)
+    }
+
+    #[inline]
+    pub const fn sub(&self, rhs: &Self) -> Self {
+        let (diff, borrow) = self.0.overflowing_sub(rhs.0);
+        Self(if borrow {
+            diff.wrapping_add(Self::MODULUS_INNER)
+        } else {
+            diff
+        })
+    }
+
+    #[inline]
+    pub const fn neg(&self) -> Self {
+        Self::zero().sub(self)
+    }
+
+    #[inline]
+    pub const fn mul(&self, rhs: &Self) -> Self {
+        // the product is smaller than 2^62, so both halves are at most p
+        let prod = self.0 as u64 * rhs.0 as u64;
+        let lo = (prod as u32) & Self::MODULUS_INNER;
+        let hi = (prod >> 31) as u32;
+        Self::reduce32(lo + hi)
+    }
+
+    #[inline]
+    pub const fn double(&self) -> Self {
+        self.add(self)
+    }
+
+    #[inline]
+    pub const fn square(&self) -> Self {
+        self.mul(self)
+    }
+
+    /// Computes the square root of this element, if it exists, as
+    /// $x^{(p + 1) / 4}$ since $p \equiv 3 \pmod 4$.
+    pub fn sqrt(&self) -> CtOption<Self> {
+        use ff::Field;
+        let t = self.pow_vartime([1 << 29]);
+        CtOption::new(t, t.square().ct_eq(self))
+    }
+}
+
+crate::impl_small_field!(
+    Mersenne31,
+    u32,
+    modulus = "0x7fffffff",
+    mul_gen = 7,
+    root_of_unity = 0x7fff_fffe,
+    root_of_unity_inv = 0x7fff_fffe,
+    delta = 0x31,
+    zeta = 0x5a35_d667,
+    from_uniform = [20, 64]
+);
+
+#[cfg(test)]
+mod test {
+    use super::Mersenne31;
+    use crate::{
+        arith_test, constants_test, from_uniform_bytes_test, legendre_test, serde_test, test,
+    };
+
+    constants_test!(Mersenne31);
+    arith_test!(Mersenne31);
+    legendre_test!(Mersenne31);
+    test!(arith, Mersenne31, sqrt_test, 1000);
+    serde_test!(Mersenne31 PrimeFieldBits);
+    from_uniform_bytes_test!(Mersenne31, 1000, L 20, L 64);
+
+    #[test]
+    fn test_reduction_edge_cases() {
+        let minus_one = -Mersenne31::one();
+        assert_eq!(minus_one.to_canonical(), Mersenne31::MODULUS_INNER - 1);
+        assert_eq!(minus_one * minus_one, Mersenne31::one());
+        assert_eq!(minus_one + minus_one, -Mersenne31::from(2));
+        assert_eq!(Mersenne31::from(1 << 31), Mersenne31::one());
+        assert_eq!(Mersenne31::from(u64::MAX), Mersenne31::from(3));
+    }
+}
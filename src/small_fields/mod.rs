@@ -0,0 +1,15 @@
+//! Prime fields of at most 64 bits used by small-field proof systems, with
+//! reductions specialised to the shape of their moduli.
+//!
+//! - [`Goldilocks`]: $p = 2^{64} - 2^{32} + 1$, two-adicity $32$.
+//! - [`BabyBear`]: $p = 15 \cdot 2^{27} + 1$, two-adicity $27$.
+//! - [`Mersenne31`]: $p = 2^{31} - 1$, two-adicity $1$, hence too small for
+//!   radix-$2$ FFTs over the field itself.
+
+mod babybear;
+mod goldilocks;
+mod mersenne31;
+
+pub use babybear::BabyBear;
+pub use goldilocks::Goldilocks;
+pub use mersenne31::Mersenne31;